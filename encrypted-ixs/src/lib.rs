@@ -22,6 +22,10 @@ mod circuits {
         entropy_p4: [u8; 32],  // Player 4 entropy (can be zero if < 4 players)
         entropy_p5: [u8; 32],  // Player 5 entropy (can be zero if < 5 players)
         entropy_p6: [u8; 32],  // Player 6 entropy (can be zero if < 6 players)
+        entropy_p7: [u8; 32],  // Player 7 entropy (can be zero if < 7 players)
+        entropy_p8: [u8; 32],  // Player 8 entropy (can be zero if < 8 players)
+        entropy_p9: [u8; 32],  // Player 9 entropy (can be zero if < 9 players)
+        entropy_p10: [u8; 32], // Player 10 entropy (can be zero if < 10 players)
     }
 
     /// Shuffle a 52-card deck using Fisher-Yates algorithm in MPC
@@ -38,7 +42,11 @@ mod circuits {
                 + input.entropy_p3[i] as u16
                 + input.entropy_p4[i] as u16
                 + input.entropy_p5[i] as u16
-                + input.entropy_p6[i] as u16) as u8;
+                + input.entropy_p6[i] as u16
+                + input.entropy_p7[i] as u16
+                + input.entropy_p8[i] as u16
+                + input.entropy_p9[i] as u16
+                + input.entropy_p10[i] as u16) as u8;
         }
         
         // Initialize ordered deck (0-51)
@@ -129,6 +137,51 @@ mod circuits {
         input_ctxt.owner.from_arcis(random_value)
     }
 
+    /// Shuffle a 36-card short deck (6 through Ace) using Fisher-Yates in MPC
+    /// Card values use the same 0-51 indexing as the full deck
+    #[instruction]
+    pub fn shuffle_short_deck(input_ctxt: Enc<Shared, ShuffleInput>) -> Enc<Shared, [u8; 36]> {
+        let input = input_ctxt.to_arcis();
+        
+        // Combine all player entropy via addition (mod 256)
+        let mut combined_entropy = input.entropy_p1;
+        for i in 0..32 {
+            combined_entropy[i] = (combined_entropy[i] as u16
+                + input.entropy_p2[i] as u16
+                + input.entropy_p3[i] as u16
+                + input.entropy_p4[i] as u16
+                + input.entropy_p5[i] as u16
+                + input.entropy_p6[i] as u16
+                + input.entropy_p7[i] as u16
+                + input.entropy_p8[i] as u16
+                + input.entropy_p9[i] as u16
+                + input.entropy_p10[i] as u16) as u8;
+        }
+        
+        // Initialize ordered short deck: ranks 6-A (offsets 4-12) in each suit
+        let mut deck = [0u8; 36];
+        for suit in 0..4 {
+            for rank in 0..9 {
+                deck[suit * 9 + rank] = (suit * 13 + rank + 4) as u8;
+            }
+        }
+        
+        // Fisher-Yates shuffle using combined entropy
+        for i in (1..36).rev() {
+            let entropy_idx = (i % 32) as usize;
+            let random_byte = combined_entropy[entropy_idx];
+            let j = (random_byte as usize) % (i + 1);
+            
+            let temp = deck[i];
+            deck[i] = deck[j];
+            deck[j] = temp;
+            
+            combined_entropy = hash_entropy(combined_entropy);
+        }
+        
+        input_ctxt.owner.from_arcis(deck)
+    }
+
     // ============================================================================
     // HELPER FUNCTIONS
     // ============================================================================
@@ -0,0 +1,366 @@
+use anchor_lang::prelude::*;
+use crate::cards::evaluator::{EvaluatedHand, evaluate_best_hand_with_rules, plays_both_hole_cards};
+use crate::game::state::Game;
+use crate::player::state::PlayerState;
+use crate::showdown::instruction::{decode_board, reveal_player_cards};
+use crate::shared::PokerError;
+use crate::types::{GameVariant, HandRank, PlayerStatus};
+
+/// Basis points denominator for jackpot shares
+const BPS: u64 = 10_000;
+
+/// Jackpot qualification and payout rules
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct JackpotConfig {
+    /// Rake moved into the pool from each raked pot (capped at the hand's rake)
+    pub drop_per_pot: u64,
+    
+    /// Weakest losing hand that triggers a bad beat (e.g. FourOfAKind)
+    pub bad_beat_rank: HandRank,
+    
+    /// Weakest primary card within `bad_beat_rank` (e.g. 11 for quad jacks)
+    pub bad_beat_primary: u8,
+    
+    /// Share of the pool paid to the bad beat loser (basis points)
+    pub loser_share: u16,
+    
+    /// Share of the pool paid to the hand's winner (basis points)
+    pub winner_share: u16,
+    
+    /// Share of the pool split among everyone else dealt in (basis points)
+    pub table_share: u16,
+    
+    /// Weakest hand that wins the high hand award
+    pub high_hand_rank: HandRank,
+    
+    /// Weakest primary card within `high_hand_rank`
+    pub high_hand_primary: u8,
+    
+    /// Share of the pool paid for a table high hand (basis points, 0 = off)
+    pub high_hand_share: u16,
+}
+
+impl Default for JackpotConfig {
+    fn default() -> Self {
+        Self {
+            drop_per_pot: 500_000, // 0.0005 SOL or 0.5 USDC
+            bad_beat_rank: HandRank::FourOfAKind,
+            bad_beat_primary: 11, // Quad jacks
+            loser_share: 5_000,
+            winner_share: 2_500,
+            table_share: 2_500,
+            high_hand_rank: HandRank::StraightFlush,
+            high_hand_primary: 0,
+            high_hand_share: 0,
+        }
+    }
+}
+
+impl JackpotConfig {
+    /// Calculate space needed for JackpotConfig
+    pub const LEN: usize = 8 + // drop_per_pot
+        1 + // bad_beat_rank
+        1 + // bad_beat_primary
+        2 + // loser_share
+        2 + // winner_share
+        2 + // table_share
+        1 + // high_hand_rank
+        1 + // high_hand_primary
+        2; // high_hand_share
+    
+    /// Shares must not pay out more than the pool
+    pub fn validate(&self) -> Result<()> {
+        let bad_beat_total = self.loser_share as u64 + self.winner_share as u64 + self.table_share as u64;
+        require!(bad_beat_total <= BPS, PokerError::InvalidJackpotConfig);
+        require!(self.high_hand_share as u64 <= BPS, PokerError::InvalidJackpotConfig);
+        Ok(())
+    }
+    
+    fn bad_beat_enabled(&self) -> bool {
+        self.loser_share > 0 || self.winner_share > 0 || self.table_share > 0
+    }
+}
+
+/// Jackpot pool for a table (PDA per game)
+/// Funds stay in the game escrow; this ledger keeps them apart from stacks and rake
+#[account]
+pub struct Jackpot {
+    /// Game this pool belongs to
+    pub game: Pubkey,
+    
+    /// Qualification and payout rules
+    pub config: JackpotConfig,
+    
+    /// Chips currently in the pool
+    pub balance: u64,
+    
+    /// Total chips paid out of the pool
+    pub total_paid: u64,
+    
+    /// Number of jackpot hits paid
+    pub hits: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Jackpot {
+    /// Calculate space needed for Jackpot account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // game
+        JackpotConfig::LEN + // config
+        8 + // balance
+        8 + // total_paid
+        8 + // hits
+        1; // bump
+}
+
+/// Jackpot hit found at a showdown
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JackpotHit {
+    /// A qualifying hand lost at showdown
+    BadBeat { loser: u8, winner: u8 },
+    /// A qualifying hand was shown down
+    HighHand { seat: u8 },
+}
+
+/// Check a hand against a (rank, primary card) threshold
+fn meets_threshold(hand: &EvaluatedHand, rank: HandRank, primary: u8) -> bool {
+    (hand.rank, hand.primary_value) >= (rank, primary)
+}
+
+/// Find a jackpot hit among the hands shown down
+/// Each entry is (seat, best hand, both hole cards play). A bad beat takes
+/// precedence over a high hand and pays only once per hand.
+pub fn find_jackpot_hit(
+    hands: &[(u8, EvaluatedHand, bool)],
+    config: &JackpotConfig,
+) -> Option<JackpotHit> {
+    let &(winner, winning_hand, _) = hands.iter().max_by_key(|(_, hand, _)| *hand)?;
+    
+    if config.bad_beat_enabled() {
+        let loser = hands
+            .iter()
+            .filter(|(_, hand, both_play)| {
+                *both_play
+                    && *hand < winning_hand
+                    && meets_threshold(hand, config.bad_beat_rank, config.bad_beat_primary)
+            })
+            .max_by_key(|(_, hand, _)| *hand);
+        
+        if let Some(&(loser, _, _)) = loser {
+            return Some(JackpotHit::BadBeat { loser, winner });
+        }
+    }
+    
+    if config.high_hand_share > 0
+        && meets_threshold(&winning_hand, config.high_hand_rank, config.high_hand_primary)
+    {
+        return Some(JackpotHit::HighHand { seat: winner });
+    }
+    
+    None
+}
+
+/// Move the hand's jackpot drop from collected rake into the pool
+pub fn feed_jackpot(game: &mut Game, jackpot: &mut Jackpot) -> u64 {
+    let drop = jackpot.config.drop_per_pot
+        .min(game.hand_rake)
+        .min(game.collected_rake);
+    
+    game.collected_rake -= drop;
+    jackpot.balance += drop;
+    
+    drop
+}
+
+/// Pay a jackpot hit from the pool into player stacks
+/// The table share is split evenly among the other players dealt in; any
+/// remainder stays in the pool.
+pub fn pay_jackpot(
+    jackpot: &mut Jackpot,
+    hit: JackpotHit,
+    player_states: &mut [PlayerState],
+) -> u64 {
+    let pool = jackpot.balance;
+    let share_of = |bps: u16| pool * bps as u64 / BPS;
+    let mut paid = 0u64;
+    
+    match hit {
+        JackpotHit::BadBeat { loser, winner } => {
+            let loser_award = share_of(jackpot.config.loser_share);
+            let winner_award = share_of(jackpot.config.winner_share);
+            player_states[loser as usize].add_winnings(loser_award);
+            player_states[winner as usize].add_winnings(winner_award);
+            paid += loser_award + winner_award;
+            
+            let table: Vec<usize> = (0..player_states.len())
+                .filter(|&seat| {
+                    let player_state = &player_states[seat];
+                    player_state.has_cards
+                        && player_state.status != PlayerStatus::Left
+                        && seat != loser as usize
+                        && seat != winner as usize
+                })
+                .collect();
+            if !table.is_empty() {
+                let each = share_of(jackpot.config.table_share) / table.len() as u64;
+                for seat in table {
+                    player_states[seat].add_winnings(each);
+                    paid += each;
+                }
+            }
+            
+            msg!(
+                "[JACKPOT] Bad beat! Seat {} loses to seat {}: paid {} of {}",
+                loser,
+                winner,
+                paid,
+                pool
+            );
+        }
+        JackpotHit::HighHand { seat } => {
+            paid = share_of(jackpot.config.high_hand_share);
+            player_states[seat as usize].add_winnings(paid);
+            
+            msg!("[JACKPOT] High hand by seat {}: paid {} of {}", seat, paid, pool);
+        }
+    }
+    
+    jackpot.balance -= paid;
+    jackpot.total_paid += paid;
+    jackpot.hits += 1;
+    
+    paid
+}
+
+/// Check the showdown for a jackpot hit and pay it
+///
+/// Hold'em and short deck only; hands run twice do not qualify.
+pub fn settle_jackpot(
+    game: &Game,
+    player_states: &mut [PlayerState],
+    jackpot: &mut Jackpot,
+) -> Result<()> {
+    if game.run_it_twice || game.variant == GameVariant::OmahaHiLo || jackpot.balance == 0 {
+        return Ok(());
+    }
+    
+    let community_cards = decode_board(&game.community_cards, game)?;
+    let rules = game.hand_rules();
+    
+    let mut hands = Vec::new();
+    for (seat, player_state) in player_states.iter().enumerate() {
+        if !game.active_players[seat] || player_state.has_folded {
+            continue;
+        }
+        
+        let hole_cards = reveal_player_cards(
+            player_state,
+            game.shuffle_session_id,
+            player_state.player,
+        )?;
+        hands.push((
+            seat as u8,
+            evaluate_best_hand_with_rules(&hole_cards, &community_cards, &rules)?,
+            plays_both_hole_cards(&hole_cards, &community_cards, &rules)?,
+        ));
+    }
+    
+    if let Some(hit) = find_jackpot_hit(&hands, &jackpot.config) {
+        pay_jackpot(jackpot, hit, player_states);
+    }
+    
+    Ok(())
+}
+
+/// Create the table's jackpot pool (authority only)
+pub fn create(ctx: Context<crate::CreateJackpot>, config: JackpotConfig) -> Result<()> {
+    config.validate()?;
+    
+    let game = &mut ctx.accounts.game;
+    let jackpot = &mut ctx.accounts.jackpot;
+    
+    jackpot.game = game.key();
+    jackpot.config = config;
+    jackpot.balance = 0;
+    jackpot.total_paid = 0;
+    jackpot.hits = 0;
+    jackpot.bump = ctx.bumps.jackpot;
+    
+    game.has_jackpot = true;
+    
+    msg!(
+        "[JACKPOT] Created for game {} (drop per pot: {})",
+        game.game_id,
+        config.drop_per_pot
+    );
+    
+    Ok(())
+}
+
+/// Update the jackpot rules; the pool balance carries over (authority only)
+pub fn configure(ctx: Context<crate::ConfigureJackpot>, config: JackpotConfig) -> Result<()> {
+    config.validate()?;
+    
+    ctx.accounts.jackpot.config = config;
+    
+    msg!("[JACKPOT] Rules updated (drop per pot: {})", config.drop_per_pot);
+    
+    Ok(())
+}
+
+/// Retire the jackpot pool between hands (authority only)
+/// The remaining pool returns to collected rake and is swept with it
+pub fn close(ctx: Context<crate::CloseJackpot>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    require!(!game.is_hand_in_progress(), PokerError::InvalidGameStage);
+    
+    let balance = ctx.accounts.jackpot.balance;
+    game.collected_rake += balance;
+    game.has_jackpot = false;
+    
+    msg!("[JACKPOT] Closed for game {}, {} returned to rake", game.game_id, balance);
+    
+    Ok(())
+}
+
+// Uses the CreateJackpot, ConfigureJackpot and CloseJackpot accounts structs in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn quads(rank: u8) -> EvaluatedHand {
+        EvaluatedHand::new(HandRank::FourOfAKind, rank, 0, [2, 0, 0, 0, 0])
+    }
+    
+    #[test]
+    fn test_jackpot_qualification() {
+        let config = JackpotConfig::default();
+        let straight_flush = EvaluatedHand::new(HandRank::StraightFlush, 9, 0, [9, 8, 7, 6, 5]);
+        
+        // Quad queens with both hole cards lose to a straight flush
+        let hands = [(0, quads(12), true), (3, straight_flush, false)];
+        assert_eq!(
+            find_jackpot_hit(&hands, &config),
+            Some(JackpotHit::BadBeat { loser: 0, winner: 3 })
+        );
+        
+        // Quad tens are below the threshold
+        let hands = [(0, quads(10), true), (3, straight_flush, false)];
+        assert_eq!(find_jackpot_hit(&hands, &config), None);
+        
+        // Quads made with one hole card do not qualify
+        let hands = [(0, quads(12), false), (3, straight_flush, false)];
+        assert_eq!(find_jackpot_hit(&hands, &config), None);
+        
+        // High hand pays the winner when no bad beat occurred
+        let high_hand = JackpotConfig { high_hand_share: 1_000, ..config };
+        let hands = [(0, quads(5), true), (3, straight_flush, false)];
+        assert_eq!(
+            find_jackpot_hit(&hands, &high_hand),
+            Some(JackpotHit::HighHand { seat: 3 })
+        );
+    }
+}
@@ -2,6 +2,7 @@
 pub mod tournament;
 pub mod rake;
 pub mod statistics;
+pub mod jackpot;
 
 // Export specific items
 pub use tournament::{
@@ -15,8 +16,24 @@ pub use tournament::{
 pub use rake::{
     RakeConfig,
     calculate_rake,
+    calculate_hand_rake,
     collect_rake,
     get_rake_for_pot,
+    rake_pot,
+    attribute_rake,
+    calculate_rewards,
+};
+pub use jackpot::{
+    Jackpot,
+    JackpotConfig,
+    JackpotHit,
+    find_jackpot_hit,
+    feed_jackpot,
+    pay_jackpot,
+    settle_jackpot,
+    create as create_jackpot_handler,
+    configure as configure_jackpot_handler,
+    close as close_jackpot_handler,
 };
 pub use statistics::{
     PlayerStats,
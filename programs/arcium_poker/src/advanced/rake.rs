@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
+use crate::game::state::Game;
+use crate::player::state::PlayerState;
 use crate::shared::PokerError;
+use crate::shared::constants::MAX_PLAYERS;
+use crate::types::PlayerStatus;
 
 /// Rake configuration
+/// The single rake policy, stored per table and applied when a hand settles
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
 pub struct RakeConfig {
     /// Rake percentage (in basis points, e.g., 250 = 2.5%)
@@ -10,11 +15,20 @@ pub struct RakeConfig {
     /// Maximum rake per hand (in lamports/tokens)
     pub rake_cap: u64,
     
+    /// Cap by number of players dealt in (index = players - 1, 0 = use `rake_cap`)
+    pub player_count_caps: [u64; MAX_PLAYERS],
+    
     /// Minimum pot size to collect rake
     pub min_pot_for_rake: u64,
     
-    /// House wallet for rake collection
-    pub house_wallet: Pubkey,
+    /// No flop, no drop: hands that end before the flop are not raked
+    pub no_flop_no_drop: bool,
+    
+    /// Share of a player's rake returned to them as rakeback (basis points)
+    pub rakeback_percentage: u16,
+    
+    /// Share of a player's rake paid to their referrer (basis points)
+    pub affiliate_percentage: u16,
     
     /// Total rake collected
     pub total_rake_collected: u64,
@@ -28,14 +42,46 @@ impl Default for RakeConfig {
         Self {
             rake_percentage: 250, // 2.5%
             rake_cap: 3_000_000, // 0.003 SOL or 3 USDC
+            player_count_caps: [0; MAX_PLAYERS],
             min_pot_for_rake: 1_000_000, // 0.001 SOL or 1 USDC
-            house_wallet: Pubkey::default(),
+            no_flop_no_drop: true,
+            rakeback_percentage: 0,
+            affiliate_percentage: 0,
             total_rake_collected: 0,
             hands_raked: 0,
         }
     }
 }
 
+impl RakeConfig {
+    /// Calculate space needed for RakeConfig
+    pub const LEN: usize = 2 + // rake_percentage
+        8 + // rake_cap
+        (8 * MAX_PLAYERS) + // player_count_caps
+        8 + // min_pot_for_rake
+        1 + // no_flop_no_drop
+        2 + // rakeback_percentage
+        2 + // affiliate_percentage
+        8 + // total_rake_collected
+        8; // hands_raked
+    
+    /// Policy with rake switched off
+    pub fn disabled() -> Self {
+        Self {
+            rake_percentage: 0,
+            ..Default::default()
+        }
+    }
+    
+    /// Rake cap for a hand dealt to `players_dealt` players
+    pub fn cap_for_players(&self, players_dealt: u8) -> u64 {
+        match players_dealt.checked_sub(1).and_then(|i| self.player_count_caps.get(i as usize)) {
+            Some(&cap) if cap > 0 => cap,
+            _ => self.rake_cap,
+        }
+    }
+}
+
 /// Calculate rake for a pot
 pub fn calculate_rake(pot_amount: u64, config: &RakeConfig) -> u64 {
     // No rake if pot is too small
@@ -58,12 +104,35 @@ pub fn get_rake_for_pot(pot_amount: u64, config: &RakeConfig) -> (u64, u64) {
     (net_pot, rake)
 }
 
+/// Calculate rake for a settled hand
+/// Applies "no flop, no drop" and the cap for the number of players dealt in
+pub fn calculate_hand_rake(
+    pot_amount: u64,
+    config: &RakeConfig,
+    players_dealt: u8,
+    saw_flop: bool,
+) -> u64 {
+    if config.no_flop_no_drop && !saw_flop {
+        return 0;
+    }
+    
+    if pot_amount < config.min_pot_for_rake {
+        return 0;
+    }
+    
+    let rake = (pot_amount * config.rake_percentage as u64) / 10_000;
+    rake.min(config.cap_for_players(players_dealt))
+}
+
 /// Collect rake from pot
 pub fn collect_rake(
     pot_amount: u64,
     config: &mut RakeConfig,
+    players_dealt: u8,
+    saw_flop: bool,
 ) -> Result<(u64, u64)> {
-    let (net_pot, rake) = get_rake_for_pot(pot_amount, config);
+    let rake = calculate_hand_rake(pot_amount, config, players_dealt, saw_flop);
+    let net_pot = pot_amount.saturating_sub(rake);
     
     if rake > 0 {
         config.total_rake_collected += rake;
@@ -80,27 +149,67 @@ pub fn collect_rake(
     Ok((net_pot, rake))
 }
 
-/// Transfer rake to house wallet
-pub fn transfer_rake_to_house(
-    game_account: &AccountInfo,
-    house_account: &AccountInfo,
-    rake_amount: u64,
-) -> Result<()> {
-    require!(
-        rake_amount > 0,
-        PokerError::InvalidBetAmount
-    );
+/// Take the house rake from the pot of a finished hand
+/// The rake is held in the game escrow as `collected_rake` until swept to the
+/// treasury, and the amount is recorded on the game for the hand
+pub fn rake_pot(game: &mut Game) -> Result<u64> {
+    let saw_flop = game.community_cards_revealed >= 3;
+    let players_dealt = game.players_dealt;
     
-    // Transfer lamports from game to house
-    **game_account.try_borrow_mut_lamports()? -= rake_amount;
-    **house_account.try_borrow_mut_lamports()? += rake_amount;
+    let (net_pot, rake) = collect_rake(game.pot, &mut game.rake, players_dealt, saw_flop)?;
     
-    msg!(
-        "[RAKE] Transferred {} to house wallet",
-        rake_amount
-    );
+    game.pot = net_pot;
+    game.collected_rake += rake;
+    game.hand_rake = rake;
     
-    Ok(())
+    msg!("[RAKE] Hand {} raked {}", game.hand_number, rake);
+    
+    Ok(rake)
+}
+
+/// Split a hand's rake between the players who paid into the pot
+/// Each player is charged in proportion to their contribution; the rounding
+/// remainder goes to the biggest contributor. A player who left mid-hand
+/// forfeits rakeback on that hand, since their account closes with the seat.
+pub fn attribute_rake(rake: u64, player_states: &mut [PlayerState]) {
+    let total_contributed: u64 = player_states
+        .iter()
+        .map(|player_state| player_state.total_bet_this_hand)
+        .sum();
+    if rake == 0 || total_contributed == 0 {
+        return;
+    }
+    
+    let mut attributed = 0;
+    for player_state in player_states.iter_mut() {
+        let share = (rake as u128 * player_state.total_bet_this_hand as u128
+            / total_contributed as u128) as u64;
+        if player_state.status != PlayerStatus::Left {
+            player_state.pending_rake += share;
+        }
+        attributed += share;
+    }
+    
+    if let Some(top) = player_states
+        .iter_mut()
+        .filter(|player_state| player_state.status != PlayerStatus::Left)
+        .max_by_key(|player_state| player_state.total_bet_this_hand)
+    {
+        top.pending_rake += rake - attributed;
+    }
+}
+
+/// Rakeback and affiliate shares of `rake` under the policy
+/// Returns (rakeback, affiliate)
+pub fn calculate_rewards(rake: u64, config: &RakeConfig, has_referrer: bool) -> (u64, u64) {
+    let rakeback = rake * config.rakeback_percentage as u64 / 10_000;
+    let affiliate = if has_referrer {
+        rake * config.affiliate_percentage as u64 / 10_000
+    } else {
+        0
+    };
+    
+    (rakeback, affiliate)
 }
 
 /// Calculate rake statistics
@@ -128,9 +237,9 @@ pub fn validate_rake_config(config: &RakeConfig) -> Result<()> {
         PokerError::InvalidGameConfig
     );
     
-    // House wallet should be valid
+    // Rakeback and affiliate shares cannot exceed the rake
     require!(
-        config.house_wallet != Pubkey::default(),
+        config.rakeback_percentage as u32 + config.affiliate_percentage as u32 <= 10_000,
         PokerError::InvalidGameConfig
     );
     
@@ -194,6 +303,67 @@ mod tests {
         assert_eq!(calculate_rake(200_000_000, &config), 3_000_000);
     }
     
+    #[test]
+    fn test_hand_rake_rules() {
+        let mut config = RakeConfig {
+            rake_percentage: 500, // 5%
+            rake_cap: 3_000_000,
+            min_pot_for_rake: 1_000_000,
+            no_flop_no_drop: true,
+            ..Default::default()
+        };
+        config.player_count_caps[1] = 1_000_000; // heads-up
+        
+        // No flop, no drop
+        assert_eq!(calculate_hand_rake(100_000_000, &config, 6, false), 0);
+        
+        // Full table uses the default cap
+        assert_eq!(calculate_hand_rake(100_000_000, &config, 6, true), 3_000_000);
+        
+        // Heads-up uses its own cap
+        assert_eq!(calculate_hand_rake(100_000_000, &config, 2, true), 1_000_000);
+        
+        // Preflop hands are raked when the rule is off
+        config.no_flop_no_drop = false;
+        assert_eq!(calculate_hand_rake(10_000_000, &config, 6, false), 500_000);
+    }
+    
+    #[test]
+    fn test_rake_attribution() {
+        let mut player_states = vec![PlayerState::default(); 3];
+        player_states[0].total_bet_this_hand = 300;
+        player_states[1].total_bet_this_hand = 600;
+        
+        attribute_rake(100, &mut player_states);
+        
+        // 1/3 and 2/3 of the rake; the remainder goes to the biggest contributor
+        assert_eq!(player_states[0].pending_rake, 33);
+        assert_eq!(player_states[1].pending_rake, 67);
+        assert_eq!(player_states[2].pending_rake, 0);
+        
+        let config = RakeConfig {
+            rakeback_percentage: 2_000, // 20%
+            affiliate_percentage: 1_000, // 10%
+            ..Default::default()
+        };
+        assert_eq!(calculate_rewards(1_000, &config, true), (200, 100));
+        assert_eq!(calculate_rewards(1_000, &config, false), (200, 0));
+    }
+    
+    #[test]
+    fn test_left_player_forfeits_rakeback() {
+        let mut player_states = vec![PlayerState::default(); 2];
+        player_states[0].total_bet_this_hand = 600;
+        player_states[0].status = PlayerStatus::Left;
+        player_states[1].total_bet_this_hand = 300;
+        
+        attribute_rake(100, &mut player_states);
+        
+        // The remainder goes to the biggest contributor still at the table
+        assert_eq!(player_states[0].pending_rake, 0);
+        assert_eq!(player_states[1].pending_rake, 34);
+    }
+    
     #[test]
     fn test_net_pot_calculation() {
         let config = RakeConfig {
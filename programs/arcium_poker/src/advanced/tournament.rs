@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::game::state::Game;
 use crate::player::state::PlayerState;
-use crate::shared::PokerError;
+use crate::shared::{PokerError, constants::{MAX_PLAYERS, MIN_PLAYERS}};
+use crate::types::AnteType;
 
 /// Tournament configuration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
@@ -18,6 +19,15 @@ pub struct TournamentConfig {
     /// Blind increase multiplier (e.g., 2 = double blinds)
     pub blind_multiplier: u8,
     
+    /// Ante at the level antes start (scaled by the blind multiplier afterwards)
+    pub starting_ante: u64,
+    
+    /// Blind level at which antes start
+    pub ante_start_level: u8,
+    
+    /// Ante format used once antes start
+    pub ante_type: AnteType,
+    
     /// Total number of players
     pub total_players: u16,
     
@@ -47,6 +57,9 @@ impl Default for TournamentConfig {
             starting_big_blind: 200,
             blind_increase_interval: 600, // 10 minutes
             blind_multiplier: 2,
+            starting_ante: 0,
+            ante_start_level: 1,
+            ante_type: AnteType::None,
             total_players: 0,
             players_remaining: 0,
             final_table_size: 9,
@@ -97,6 +110,13 @@ pub fn initialize_tournament(
     config: TournamentConfig,
     bump: u8,
 ) -> Result<()> {
+    // The final table must fit on a single table
+    require!(
+        config.final_table_size as usize >= MIN_PLAYERS
+            && config.final_table_size as usize <= MAX_PLAYERS,
+        PokerError::InvalidGameConfig
+    );
+    
     tournament_state.tournament_id = tournament_id;
     tournament_state.config = config;
     tournament_state.prize_pool = 0;
@@ -146,10 +166,18 @@ pub fn increase_blinds(
     tournament_state.config.last_blind_increase = current_time;
     tournament_state.config.blind_level += 1;
     
+    // Antes follow the same schedule once they kick in
+    let ante = get_ante_schedule(tournament_state.config.blind_level, &tournament_state.config);
+    if ante > 0 {
+        game.ante = ante;
+        game.ante_type = tournament_state.config.ante_type;
+    }
+    
     msg!(
-        "[TOURNAMENT] Blinds increased to {}/{}. Level: {}",
+        "[TOURNAMENT] Blinds increased to {}/{} (ante {}). Level: {}",
         game.small_blind,
         game.big_blind,
+        game.ante,
         tournament_state.config.blind_level
     );
     
@@ -229,6 +257,16 @@ pub fn get_blind_schedule(level: u8, starting_blind: u64, multiplier: u8) -> (u6
     (small_blind, big_blind)
 }
 
+/// Get ante for a blind level (0 before antes start)
+pub fn get_ante_schedule(level: u8, config: &TournamentConfig) -> u64 {
+    if config.ante_type == AnteType::None || level < config.ante_start_level {
+        return 0;
+    }
+    
+    let factor = (config.blind_multiplier as u64).pow((level - config.ante_start_level) as u32);
+    config.starting_ante * factor
+}
+
 /// Check if tournament is complete
 pub fn is_tournament_complete(tournament_state: &TournamentState) -> bool {
     tournament_state.config.players_remaining <= 1
@@ -6,6 +6,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use crate::game::state::Game;
+use crate::shared::PokerError;
 
 /// Arcium MXE Program ID on Devnet
 pub const ARCIUM_PROGRAM_ID: &str = "ArciumMXE11111111111111111111111111111111111";
@@ -14,6 +15,27 @@ pub const ARCIUM_PROGRAM_ID: &str = "ArciumMXE1111111111111111111111111111111111
 pub const SHUFFLE_COMP_DEF_OFFSET: u32 = 1;
 pub const DEAL_COMP_DEF_OFFSET: u32 = 2;
 pub const REVEAL_COMP_DEF_OFFSET: u32 = 3;
+pub const SHORT_DECK_SHUFFLE_COMP_DEF_OFFSET: u32 = 4;
+
+/// Circuit instruction indices (declaration order in encrypted-ixs)
+pub const SHUFFLE_DECK_IX_INDEX: u8 = 0;
+pub const SHUFFLE_SHORT_DECK_IX_INDEX: u8 = 4;
+
+/// Circuit instruction behind a shuffle computation definition
+pub fn shuffle_instruction_index(comp_def_offset: u32) -> Result<u8> {
+    match comp_def_offset {
+        SHUFFLE_COMP_DEF_OFFSET => Ok(SHUFFLE_DECK_IX_INDEX),
+        SHORT_DECK_SHUFFLE_COMP_DEF_OFFSET => Ok(SHUFFLE_SHORT_DECK_IX_INDEX),
+        _ => err!(PokerError::InvalidGameConfig),
+    }
+}
+
+/// Computation offset for a hand's shuffle (unique per game and hand)
+/// The game ID is spread with a golden-ratio multiplier so consecutive games
+/// don't collide, then mixed with the hand number.
+pub fn shuffle_computation_offset(game_id: u64, hand_number: u64) -> [u8; 8] {
+    (game_id.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ hand_number).to_le_bytes()
+}
 
 /// Encrypted data wrapper for MPC
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -163,8 +185,8 @@ pub fn handle_shuffle_callback(
     msg!("[ARCIUM] Computation ID: {:?}", &computation_id[..8]);
     msg!("[ARCIUM] Output length: {} bytes", encrypted_output.len());
     
-    // Verify this is for our game
-    let expected_offset = game.game_id.to_le_bytes();
+    // Verify this is for our game's current hand
+    let expected_offset = shuffle_computation_offset(game.game_id, game.hand_number);
     require!(
         computation_id[..8] == expected_offset,
         ErrorCode::InvalidMxeCallback
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
-use crate::shared::constants::DECK_SIZE;
+use crate::shared::constants::{DECK_SIZE, SHORT_DECK_SIZE, EMPTY_CARD_SLOT};
 use crate::shared::PokerError;
 use super::integration::{MxeInstructionData, EncryptedData};
 
@@ -8,6 +8,7 @@ use super::integration::{MxeInstructionData, EncryptedData};
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct ShuffleResult {
     /// Encrypted and shuffled card indices
+    /// (short deck fills the first 36 slots, the rest hold EMPTY_CARD_SLOT)
     pub shuffled_indices: [u8; DECK_SIZE],
     
     /// Commitment/hash of the shuffle (for verification)
@@ -51,6 +52,9 @@ pub struct MxeShuffleParams<'info> {
     
     /// Game ID
     pub game_id: u64,
+    
+    /// Number of cards in the deck (52, or 36 for short deck)
+    pub deck_size: usize,
 }
 
 /// Parameters for MPC shuffle
@@ -112,8 +116,13 @@ pub fn mpc_shuffle_deck_with_mxe<'info>(
         params.player_pubkeys.len() == params.encrypted_entropy.len(),
         PokerError::ArciumMpcFailed
     );
+    require!(
+        params.deck_size == DECK_SIZE || params.deck_size == SHORT_DECK_SIZE,
+        PokerError::InvalidGameConfig
+    );
     
     msg!("[ARCIUM MPC] Initiating shuffle for game {}", params.game_id);
+    msg!("[ARCIUM MPC] Deck size: {}", params.deck_size);
     msg!("[ARCIUM MPC] Players participating: {}", params.player_pubkeys.len());
     
     // Check if MXE accounts are provided
@@ -137,7 +146,7 @@ pub fn mpc_shuffle_deck_with_mxe<'info>(
             let commitment = generate_commitment(&params.encrypted_entropy, &session_id);
             
             // For integrated MXE, we do the shuffle directly
-            let shuffled_indices = perform_integrated_shuffle(&params.encrypted_entropy, &params.player_pubkeys, params.game_id)?;
+            let shuffled_indices = perform_integrated_shuffle(&params.encrypted_entropy, &params.player_pubkeys, params.game_id, params.deck_size)?;
             
             msg!("[ARCIUM MPC] Integrated shuffle complete!");
             
@@ -163,6 +172,12 @@ pub fn mpc_shuffle_deck_with_mxe<'info>(
         // Queue MPC computation via CPI
         use super::integration::queue_mxe_computation;
         
+        let shuffle_ix_index = if params.deck_size == SHORT_DECK_SIZE {
+            super::integration::SHUFFLE_SHORT_DECK_IX_INDEX
+        } else {
+            super::integration::SHUFFLE_DECK_IX_INDEX
+        };
+        
         let computation_id = queue_mxe_computation(
             mxe_program,
             comp_def,
@@ -170,7 +185,7 @@ pub fn mpc_shuffle_deck_with_mxe<'info>(
             cluster,
             computation_account, // Actual computation account from context
             authority, // Actual authority signer from context
-            0, // shuffle_deck instruction index
+            shuffle_ix_index,
             &encrypted_inputs,
             params.computation_offset,
         )?;
@@ -201,7 +216,7 @@ pub fn mpc_shuffle_deck_with_mxe<'info>(
     let mut encrypted_inputs = Vec::new();
     
     // Initial deck (0-51 in order)
-    let initial_deck = create_initial_deck();
+    let initial_deck = create_initial_deck(params.deck_size);
     encrypted_inputs.push(EncryptedData {
         ciphertext: hash_to_ciphertext(&initial_deck),
         nonce: generate_nonce(params.game_id),
@@ -229,7 +244,7 @@ pub fn mpc_shuffle_deck_with_mxe<'info>(
     let commitment = generate_commitment(&params.encrypted_entropy, &session_id);
     
     // Mock mode: Use deterministic shuffle for testing
-    let shuffled_indices = secure_shuffle_with_entropy(&params.encrypted_entropy)?;
+    let shuffled_indices = secure_shuffle_with_entropy(&params.encrypted_entropy, params.deck_size)?;
     let session_id = generate_session_id(params.game_id, &params.player_pubkeys);
     let commitment = generate_commitment(&params.encrypted_entropy, &session_id);
     
@@ -293,6 +308,7 @@ pub fn mpc_shuffle_deck(params: ShuffleParams) -> Result<ShuffleResult> {
         computation_offset: params.game_id.to_le_bytes(),
         player_pubkeys: params.player_pubkeys.clone(),
         game_id: params.game_id,
+        deck_size: DECK_SIZE,
     };
     
     mpc_shuffle_deck_with_mxe(mxe_params)
@@ -307,6 +323,7 @@ fn perform_integrated_shuffle(
     encrypted_entropy: &[[u8; 32]],
     player_pubkeys: &[Pubkey],
     game_id: u64,
+    deck_size: usize,
 ) -> Result<[u8; DECK_SIZE]> {
     msg!("[INTEGRATED MXE] Performing shuffle with {} entropy sources", encrypted_entropy.len());
     
@@ -330,10 +347,10 @@ fn perform_integrated_shuffle(
     }
     
     // Perform Fisher-Yates shuffle using combined entropy
-    let mut deck = create_initial_deck();
+    let mut deck = create_initial_deck(deck_size);
     let mut entropy_index = 0;
     
-    for i in (1..DECK_SIZE).rev() {
+    for i in (1..deck_size).rev() {
         // Get random index from entropy
         let random_byte = combined_entropy[entropy_index % 32];
         entropy_index += 1;
@@ -425,13 +442,9 @@ fn generate_session_id_from_offset(offset: [u8; 8]) -> [u8; 32] {
 /// 
 /// In production with MXE deployed, this computation happens in MPC across
 /// Arcium network nodes. For development, we use deterministic shuffle.
-fn secure_shuffle_with_entropy(player_entropy: &[[u8; 32]]) -> Result<[u8; DECK_SIZE]> {
-    let mut indices = [0u8; DECK_SIZE];
-    
-    // Initialize with sequential indices (0-51)
-    for i in 0..DECK_SIZE {
-        indices[i] = i as u8;
-    }
+fn secure_shuffle_with_entropy(player_entropy: &[[u8; 32]], deck_size: usize) -> Result<[u8; DECK_SIZE]> {
+    // Initialize with the unshuffled deck for this variant
+    let mut indices = create_initial_deck(deck_size);
     
     // Combine all player entropy using XOR and hashing
     // This creates a single source of randomness that no single player controls
@@ -442,7 +455,7 @@ fn secure_shuffle_with_entropy(player_entropy: &[[u8; 32]]) -> Result<[u8; DECK_
     let mut rng_state = initialize_rng(&combined_entropy);
     
     // Fisher-Yates shuffle with secure randomness
-    for i in (1..DECK_SIZE).rev() {
+    for i in (1..deck_size).rev() {
         let j = secure_random_index(&mut rng_state, i + 1);
         indices.swap(i, j);
     }
@@ -499,7 +512,14 @@ fn generate_session_id(game_id: u64, players: &[Pubkey]) -> [u8; 32] {
 
 // Helper functions for secure shuffle
 
-fn create_initial_deck() -> [u8; DECK_SIZE] {
+fn create_initial_deck(deck_size: usize) -> [u8; DECK_SIZE] {
+    if deck_size == SHORT_DECK_SIZE {
+        // Short deck: 36 card indices followed by empty slots
+        let mut deck = [EMPTY_CARD_SLOT; DECK_SIZE];
+        deck[..SHORT_DECK_SIZE].copy_from_slice(&crate::cards::deck::short_deck_indices());
+        return deck;
+    }
+    
     let mut deck = [0u8; DECK_SIZE];
     for i in 0..DECK_SIZE {
         deck[i] = i as u8;
@@ -1,10 +1,35 @@
 use anchor_lang::prelude::*;
 use crate::game::state::Game;
 use crate::player::state::PlayerState;
-use crate::types::{PlayerAction, PlayerStatus, GameStage};
+use crate::types::{PlayerAction, PlayerActionParam, PlayerStatus, GameStage};
 use crate::shared::PokerError;
 use super::validator::*;
 
+/// Handle a player action built against `hand_number` and `action_seq`
+/// Rejects stale or replayed actions, then advances the action sequence
+pub fn handle_action(
+    game: &mut Game,
+    player_state: &mut PlayerState,
+    action: PlayerActionParam,
+    hand_number: u64,
+    action_seq: u64,
+) -> Result<()> {
+    validate_action_sequence(game, hand_number, action_seq)?;
+    
+    match action {
+        PlayerActionParam::Fold => handle_fold(game, player_state)?,
+        PlayerActionParam::Check => handle_check(game, player_state)?,
+        PlayerActionParam::Call => handle_call(game, player_state)?,
+        PlayerActionParam::Bet { amount } => handle_bet(game, player_state, amount)?,
+        PlayerActionParam::Raise { amount } => handle_raise(game, player_state, amount)?,
+        PlayerActionParam::AllIn => handle_all_in(game, player_state)?,
+    }
+    
+    game.action_seq += 1;
+    
+    Ok(())
+}
+
 /// Handle player fold action
 pub fn handle_fold(
     game: &mut Game,
@@ -13,6 +38,7 @@ pub fn handle_fold(
     // Validate
     validate_betting_stage(game)?;
     validate_player_turn(game, player_state.seat_index)?;
+    player_state.record_action(game);
     validate_fold()?;
     
     // Execute fold
@@ -45,6 +71,7 @@ pub fn handle_check(
     // Validate
     validate_betting_stage(game)?;
     validate_player_turn(game, player_state.seat_index)?;
+    player_state.record_action(game);
     
     // Allow check if player has matched current bet (including blinds)
     require!(
@@ -71,6 +98,7 @@ pub fn handle_call(
     // Validate
     validate_betting_stage(game)?;
     validate_player_turn(game, player_state.seat_index)?;
+    player_state.record_action(game);
     
     let call_amount = validate_call(game, player_state)?;
     
@@ -109,6 +137,7 @@ pub fn handle_raise(
     // Validate
     validate_betting_stage(game)?;
     validate_player_turn(game, player_state.seat_index)?;
+    player_state.record_action(game);
     validate_raise(game, player_state, raise_amount)?;
     
     // Calculate total amount to bet
@@ -151,6 +180,7 @@ pub fn handle_bet(
     // Validate
     validate_betting_stage(game)?;
     validate_player_turn(game, player_state.seat_index)?;
+    player_state.record_action(game);
     validate_bet(game, player_state, bet_amount)?;
     
     // Execute bet
@@ -188,6 +218,7 @@ pub fn handle_all_in(
     // Validate
     validate_betting_stage(game)?;
     validate_player_turn(game, player_state.seat_index)?;
+    player_state.record_action(game);
     
     let all_in_amount = validate_all_in(player_state)?;
     
@@ -265,16 +296,16 @@ pub fn post_big_blind(
 /// Advance to next active player
 fn advance_to_next_player(game: &mut Game) -> Result<()> {
     let start_index = game.current_player_index;
-    let mut next_index = (start_index + 1) % game.player_count;
+    let mut next_index = (start_index + 1) % game.seat_count();
     
     // Find next active player who hasn't folded or gone all-in
     let mut found = false;
-    for _ in 0..game.player_count {
+    for _ in 0..game.seat_count() {
         if game.active_players[next_index as usize] {
             found = true;
             break;
         }
-        next_index = (next_index + 1) % game.player_count;
+        next_index = (next_index + 1) % game.seat_count();
     }
     
     // If only one player left, end the hand
@@ -291,14 +322,14 @@ fn advance_to_next_player(game: &mut Game) -> Result<()> {
 }
 
 /// Advance to next player or next stage if betting round is complete
-fn advance_to_next_player_or_stage(game: &mut Game) -> Result<()> {
+pub(crate) fn advance_to_next_player_or_stage(game: &mut Game) -> Result<()> {
     // Mark current player as having acted
     game.players_acted[game.current_player_index as usize] = true;
     
     // Check if betting round is complete
     // Round is complete when all active players have acted and matched the current bet
     let mut all_acted = true;
-    for i in 0..game.player_count as usize {
+    for i in 0..game.seat_count() as usize {
         if !game.active_players[i] {
             continue; // Skip folded/inactive players
         }
@@ -318,15 +349,15 @@ fn advance_to_next_player_or_stage(game: &mut Game) -> Result<()> {
     
     // Find next active player who hasn't acted yet (or loop back)
     let start_index = game.current_player_index;
-    let mut next_index = (start_index + 1) % game.player_count;
+    let mut next_index = (start_index + 1) % game.seat_count();
     let mut found = false;
     
-    for _ in 0..game.player_count {
+    for _ in 0..game.seat_count() {
         if game.active_players[next_index as usize] {
             found = true;
             break;
         }
-        next_index = (next_index + 1) % game.player_count;
+        next_index = (next_index + 1) % game.seat_count();
     }
     
     if !found {
@@ -339,4 +370,49 @@ fn advance_to_next_player_or_stage(game: &mut Game) -> Result<()> {
     game.last_action_at = Clock::get()?.unix_timestamp;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::test_game;
+    use crate::player::leave::forfeit_hand;
+    
+    #[test]
+    fn test_stale_action_rejected() {
+        let mut game = test_game();
+        game.add_player(Pubkey::new_unique()).unwrap();
+        game.add_player(Pubkey::new_unique()).unwrap();
+        game.stage = GameStage::PreFlop;
+        game.hand_number = 4;
+        game.action_seq = 7;
+        let mut player_state = PlayerState { chip_stack: 100, ..Default::default() };
+        
+        assert!(validate_action_sequence(&game, 4, 7).is_ok());
+        
+        // An action built before the last one landed, or replayed from an
+        // earlier hand, changes nothing
+        for (hand_number, action_seq) in [(4, 6), (4, 8), (3, 7)] {
+            let result = handle_action(
+                &mut game,
+                &mut player_state,
+                PlayerActionParam::Fold,
+                hand_number,
+                action_seq,
+            );
+            assert_eq!(result.unwrap_err(), PokerError::StaleAction.into());
+        }
+        assert_eq!(game.action_seq, 7);
+        assert!(!player_state.has_folded);
+        
+        // The other player leaving ends the hand, so an action built
+        // before they left is stale
+        let mut leaver = PlayerState { seat_index: 1, chip_stack: 100, ..Default::default() };
+        forfeit_hand(&mut game, &mut leaver).unwrap();
+        assert_eq!(game.stage, GameStage::Finished);
+        assert_eq!(game.action_seq, 8);
+        
+        let result = handle_action(&mut game, &mut player_state, PlayerActionParam::Fold, 4, 7);
+        assert_eq!(result.unwrap_err(), PokerError::StaleAction.into());
+    }
+}
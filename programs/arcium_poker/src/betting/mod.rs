@@ -14,6 +14,7 @@ pub use instruction::{
     handle_raise,
     handle_bet,
     handle_all_in,
+    handle_action,
     post_small_blind,
     post_big_blind,
 };
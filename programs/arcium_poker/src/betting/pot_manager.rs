@@ -3,10 +3,12 @@
 
 use anchor_lang::prelude::*;
 use super::state::SidePot;
+use crate::game::state::Game;
+use crate::player::state::PlayerState;
 use crate::shared::constants::MAX_PLAYERS;
 use crate::shared::PokerError;
 
-const MAX_SIDE_POTS: usize = 6; // Max side pots = max players
+const MAX_SIDE_POTS: usize = MAX_PLAYERS; // Max side pots = max players
 
 /// Pot manager for handling main pot and side pots
 pub struct PotManager {
@@ -21,6 +23,12 @@ pub struct PotManager {
     
     /// Player contributions in current round
     pub player_contributions: [u64; MAX_PLAYERS],
+    
+    /// Dead money (antes) included in the pot but not in any player's contribution
+    pub dead_money: u64,
+    
+    /// Antes posted by each seat (part of `dead_money`)
+    pub ante_contributions: [u64; MAX_PLAYERS],
 }
 
 impl PotManager {
@@ -30,7 +38,75 @@ impl PotManager {
             side_pots: [SidePot::default(); MAX_SIDE_POTS],
             side_pot_count: 0,
             player_contributions: [0; MAX_PLAYERS],
+            dead_money: 0,
+            ante_contributions: [0; MAX_PLAYERS],
+        }
+    }
+    
+    /// Rebuild the pots of a hand from what each seat put in
+    /// Antes and dead blinds are dead money, the rest is live contribution.
+    /// Players still in the hand are eligible; the pot above the highest
+    /// all-in is only open to players who were not all-in.
+    pub fn from_hand(game: &Game, player_states: &[PlayerState]) -> Result<Self> {
+        let seat_count = game.seat_count() as usize;
+        let mut pot_manager = Self::new();
+        let mut all_in_players = [false; MAX_PLAYERS];
+        let mut active_players = [false; MAX_PLAYERS];
+        
+        for (seat, player_state) in player_states.iter().enumerate().take(seat_count) {
+            if !game.is_seat_occupied(seat as u8) {
+                continue;
+            }
+            
+            pot_manager.add_ante(seat, player_state.ante_this_hand);
+            pot_manager.add_bet(seat, player_state.total_bet_this_hand - player_state.ante_this_hand);
+            active_players[seat] = game.active_players[seat] && !player_state.has_folded;
+            all_in_players[seat] = active_players[seat] && player_state.is_all_in;
+        }
+        
+        require!(
+            pot_manager.get_total_pot() == game.pot,
+            PokerError::InvalidGameConfig
+        );
+        
+        pot_manager.calculate_side_pots(seat_count, &all_in_players, &active_players)?;
+        
+        if pot_manager.side_pot_count > 0 && pot_manager.main_pot > 0 {
+            let mut top_pot = SidePot::new(pot_manager.main_pot);
+            for seat in (0..seat_count).filter(|&seat| active_players[seat] && !all_in_players[seat]) {
+                top_pot.add_eligible_player(seat);
+            }
+            
+            require!(
+                (pot_manager.side_pot_count as usize) < MAX_SIDE_POTS,
+                PokerError::InvalidGameConfig
+            );
+            pot_manager.side_pots[pot_manager.side_pot_count as usize] = top_pot;
+            pot_manager.side_pot_count += 1;
+            pot_manager.main_pot = 0;
+        }
+        
+        Ok(pot_manager)
+    }
+    
+    /// Take the house rake out of the pots
+    /// The lowest pot, which every player contests, pays first
+    pub fn take_rake(&mut self, rake: u64) -> Result<()> {
+        let mut remaining = rake;
+        
+        for side_pot in self.side_pots[..self.side_pot_count as usize].iter_mut() {
+            let taken = remaining.min(side_pot.amount);
+            side_pot.amount -= taken;
+            remaining -= taken;
         }
+        
+        let taken = remaining.min(self.main_pot);
+        self.main_pot -= taken;
+        remaining -= taken;
+        
+        require!(remaining == 0, PokerError::InvalidGameConfig);
+        
+        Ok(())
     }
     
     /// Add bet to pot
@@ -39,6 +115,19 @@ impl PotManager {
         self.main_pot += amount;
     }
     
+    /// Add dead money (antes) to pot
+    pub fn add_dead_money(&mut self, amount: u64) {
+        self.dead_money += amount;
+        self.main_pot += amount;
+    }
+    
+    /// Add a seat's ante to the pot as dead money
+    /// A player all-in for the ante alone can win each ante only up to their own
+    pub fn add_ante(&mut self, seat_index: usize, amount: u64) {
+        self.ante_contributions[seat_index] += amount;
+        self.add_dead_money(amount);
+    }
+    
     /// Calculate and create side pots for all-in scenarios
     /// This should be called at the end of each betting round
     pub fn calculate_side_pots(
@@ -73,14 +162,15 @@ impl PotManager {
                 continue;
             }
             
-            let level_contribution = all_in_amount - previous_level;
             let mut pot_amount = 0u64;
             let mut side_pot = SidePot::new(0);
             
             // Calculate pot amount and eligible players
+            // Chips from folded players stay in the pots they reached
             for i in 0..player_count {
-                if active_players[i] && self.player_contributions[i] >= *all_in_amount {
-                    pot_amount += level_contribution;
+                let contribution = self.player_contributions[i];
+                pot_amount += contribution.min(*all_in_amount) - contribution.min(previous_level);
+                if active_players[i] && contribution >= *all_in_amount {
                     side_pot.add_eligible_player(i);
                 }
             }
@@ -98,20 +188,100 @@ impl PotManager {
             previous_level = *all_in_amount;
         }
         
-        // Remaining goes to main pot (for players not all-in)
-        let mut main_pot_amount = 0u64;
-        for i in 0..player_count {
-            if active_players[i] && self.player_contributions[i] > previous_level {
-                main_pot_amount += self.player_contributions[i] - previous_level;
-            }
+        // Dead money belongs below every live pot
+        if self.dead_money > 0 {
+            self.add_dead_money_pots(player_count, all_in_players, active_players)?;
         }
         
+        // Remaining goes to main pot (for players not all-in)
+        let main_pot_amount: u64 = self.player_contributions[..player_count]
+            .iter()
+            .map(|&contribution| contribution.saturating_sub(previous_level))
+            .sum();
+        
         // Adjust main pot
         self.main_pot = main_pot_amount;
         
         Ok(())
     }
     
+    /// Place dead money in pots below the live side pots
+    ///
+    /// A player all-in for the ante alone has no live contribution and can win
+    /// each player's ante only up to their own, so the antes are split into
+    /// levels like side pots. Dead money above the lowest of those levels goes
+    /// to the lowest live pot, or its own pot when there is none.
+    fn add_dead_money_pots(
+        &mut self,
+        player_count: usize,
+        all_in_players: &[bool; MAX_PLAYERS],
+        active_players: &[bool; MAX_PLAYERS],
+    ) -> Result<()> {
+        // What each active player can win from each ante (None = all of it)
+        let ante_cap = |i: usize| -> Option<u64> {
+            (all_in_players[i] && self.player_contributions[i] == 0)
+                .then_some(self.ante_contributions[i])
+        };
+        
+        let mut ante_levels: Vec<u64> = (0..player_count)
+            .filter(|&i| active_players[i])
+            .filter_map(ante_cap)
+            .collect();
+        ante_levels.sort_unstable();
+        ante_levels.dedup();
+        
+        let mut dead_pots = Vec::new();
+        let mut previous_level = 0u64;
+        let mut remaining = self.dead_money;
+        
+        for level in ante_levels {
+            let amount: u64 = self.ante_contributions[..player_count]
+                .iter()
+                .map(|&ante| ante.min(level) - ante.min(previous_level))
+                .sum();
+            
+            let mut dead_pot = SidePot::new(amount);
+            for (i, &active) in active_players.iter().enumerate().take(player_count) {
+                if active && ante_cap(i).is_none_or(|cap| cap >= level) {
+                    dead_pot.add_eligible_player(i);
+                }
+            }
+            
+            if amount > 0 {
+                dead_pots.push(dead_pot);
+                remaining -= amount;
+            }
+            previous_level = level;
+        }
+        
+        // The rest is open to every player with a live stake
+        if remaining > 0 {
+            if self.side_pot_count > 0 {
+                self.side_pots[0].amount += remaining;
+            } else {
+                let mut dead_pot = SidePot::new(remaining);
+                for (i, &active) in active_players.iter().enumerate().take(player_count) {
+                    if active && ante_cap(i).is_none() {
+                        dead_pot.add_eligible_player(i);
+                    }
+                }
+                dead_pots.push(dead_pot);
+            }
+        }
+        
+        let live_count = self.side_pot_count as usize;
+        require!(
+            live_count + dead_pots.len() <= MAX_SIDE_POTS,
+            PokerError::InvalidGameConfig
+        );
+        
+        self.side_pots.copy_within(0..live_count, dead_pots.len());
+        self.side_pots[..dead_pots.len()].copy_from_slice(&dead_pots);
+        self.side_pot_count += dead_pots.len() as u8;
+        
+        Ok(())
+    }
+    
     /// Get total pot (main + all side pots)
     pub fn get_total_pot(&self) -> u64 {
         let mut total = self.main_pot;
@@ -129,6 +299,8 @@ impl PotManager {
     /// Reset for new hand
     pub fn reset_for_new_hand(&mut self) {
         self.main_pot = 0;
+        self.dead_money = 0;
+        self.ante_contributions = [0; MAX_PLAYERS];
         self.side_pots = [SidePot::default(); MAX_SIDE_POTS];
         self.side_pot_count = 0;
         self.player_contributions = [0; MAX_PLAYERS];
@@ -138,6 +310,12 @@ impl PotManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::test_utils::seated_game;
+    use crate::advanced::{rake_pot, RakeConfig};
+    use crate::cards::evaluator::EvaluatedHand;
+    use crate::security::validate_chip_conservation;
+    use crate::showdown::{determine_all_winners, distribute_winnings};
+    use crate::types::{GameStage, HandRank};
     
     #[test]
     fn test_simple_pot() {
@@ -170,4 +348,163 @@ mod tests {
         // Main pot: (100-50) * 2 = 100 (only players 1 and 2 eligible)
         assert_eq!(pot_manager.main_pot, 100);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_dead_money_goes_to_lowest_pot() {
+        let mut pot_manager = PotManager::new();
+        pot_manager.add_dead_money(30); // 3 x 10 ante
+        pot_manager.add_bet(0, 50);  // All-in
+        pot_manager.add_bet(1, 100);
+        pot_manager.add_bet(2, 100);
+        
+        let mut all_in = [false; MAX_PLAYERS];
+        all_in[0] = true;
+        let mut active = [false; MAX_PLAYERS];
+        active[0] = true;
+        active[1] = true;
+        active[2] = true;
+        
+        pot_manager.calculate_side_pots(3, &all_in, &active).unwrap();
+        
+        // Side pot 0: 50 * 3 + 30 antes
+        assert_eq!(pot_manager.side_pots[0].amount, 180);
+        assert_eq!(pot_manager.main_pot, 100);
+        assert_eq!(pot_manager.get_total_pot(), 280);
+    }
+    
+    #[test]
+    fn test_all_in_for_ante_only() {
+        let mut pot_manager = PotManager::new();
+        pot_manager.add_ante(0, 5); // Seat 0 could only post 5 of a 10 ante
+        pot_manager.add_ante(1, 10);
+        pot_manager.add_ante(2, 10);
+        pot_manager.add_bet(1, 100);
+        pot_manager.add_bet(2, 100);
+        
+        let mut all_in = [false; MAX_PLAYERS];
+        all_in[0] = true;
+        let mut active = [false; MAX_PLAYERS];
+        active[0] = true;
+        active[1] = true;
+        active[2] = true;
+        
+        pot_manager.calculate_side_pots(3, &all_in, &active).unwrap();
+        
+        // Seat 0 wins at most 5 from each ante
+        assert_eq!(pot_manager.side_pot_count, 2);
+        assert_eq!(pot_manager.side_pots[0].amount, 15);
+        assert_eq!(pot_manager.side_pots[0].player_count, 3);
+        
+        // The rest of the antes and the live bets are for seats 1 and 2
+        assert_eq!(pot_manager.side_pots[1].amount, 10);
+        assert_eq!(pot_manager.side_pots[1].player_count, 2);
+        assert!(!pot_manager.side_pots[1].is_eligible(0));
+        assert_eq!(pot_manager.main_pot, 200);
+        assert_eq!(pot_manager.get_total_pot(), 225);
+    }
+    
+    #[test]
+    fn test_partial_antes_split_into_levels() {
+        let mut pot_manager = PotManager::new();
+        pot_manager.add_ante(0, 3); // All-in for part of the ante
+        pot_manager.add_ante(1, 6); // All-in for part of the ante
+        pot_manager.add_ante(2, 10);
+        pot_manager.add_ante(3, 10);
+        pot_manager.add_bet(2, 40); // All-in
+        pot_manager.add_bet(3, 100);
+        
+        let mut all_in = [false; MAX_PLAYERS];
+        all_in[0] = true;
+        all_in[1] = true;
+        all_in[2] = true;
+        let mut active = [false; MAX_PLAYERS];
+        for seat in active.iter_mut().take(4) {
+            *seat = true;
+        }
+        
+        pot_manager.calculate_side_pots(4, &all_in, &active).unwrap();
+        
+        assert_eq!(pot_manager.side_pot_count, 3);
+        
+        // 3 from each ante, open to everyone
+        assert_eq!(pot_manager.side_pots[0].amount, 12);
+        assert_eq!(pot_manager.side_pots[0].player_count, 4);
+        
+        // Up to 6 from each ante: 3 from seats 1, 2 and 3
+        assert_eq!(pot_manager.side_pots[1].amount, 9);
+        assert_eq!(pot_manager.side_pots[1].player_count, 3);
+        assert!(!pot_manager.side_pots[1].is_eligible(0));
+        
+        // The rest of the antes join the lowest live pot
+        assert_eq!(pot_manager.side_pots[2].amount, 80 + 8);
+        assert_eq!(pot_manager.side_pots[2].player_count, 2);
+        assert_eq!(pot_manager.main_pot, 60);
+        assert_eq!(pot_manager.get_total_pot(), 29 + 140);
+    }
+    
+    #[test]
+    fn test_showdown_settles_partial_ante_all_in() {
+        let (mut game, mut states) = seated_game(&[5, 200, 200]);
+        game.rake = RakeConfig {
+            rake_percentage: 200,
+            rake_cap: 100,
+            min_pot_for_rake: 0,
+            no_flop_no_drop: false,
+            ..Default::default()
+        };
+        for player_state in states.iter_mut().take(3) {
+            game.pot += player_state.post_ante(10);
+        }
+        // Seat 0 is all-in for half the ante; seats 1 and 2 bet on
+        game.pot += states[1].post_blind(100) + states[2].post_blind(100);
+        game.stage = GameStage::Showdown;
+        
+        let mut pot_manager = PotManager::from_hand(&game, &states).unwrap();
+        assert_eq!(pot_manager.main_pot, 0);
+        assert_eq!(pot_manager.side_pot_count, 3);
+        assert_eq!(pot_manager.side_pots[0].amount, 15);
+        assert_eq!(pot_manager.side_pots[1].amount, 10);
+        assert_eq!(pot_manager.side_pots[2].amount, 200);
+        assert!(!pot_manager.side_pots[2].is_eligible(0));
+        
+        let rake = rake_pot(&mut game).unwrap();
+        pot_manager.take_rake(rake).unwrap();
+        assert_eq!(rake, 4);
+        assert_eq!(pot_manager.get_total_pot(), game.pot);
+        
+        // Seat 0 holds the best hand but only wins its share of the antes
+        let hands = [
+            (0, EvaluatedHand::new(HandRank::FourOfAKind, 12, 0, [2, 0, 0, 0, 0])),
+            (1, EvaluatedHand::new(HandRank::Flush, 14, 0, [14, 9, 7, 5, 3])),
+            (2, EvaluatedHand::new(HandRank::OnePair, 9, 0, [9, 14, 8, 4, 0])),
+        ];
+        let winners = determine_all_winners(
+            &hands,
+            pot_manager.main_pot,
+            &pot_manager.side_pots,
+            pot_manager.side_pot_count,
+        );
+        assert_eq!(winners, vec![(0, 11), (1, 210)]);
+        
+        distribute_winnings(&mut game, &mut states, &winners).unwrap();
+        assert_eq!((states[0].chip_stack, states[1].chip_stack, states[2].chip_stack), (11, 300, 90));
+        validate_chip_conservation(&game, &states, 0).unwrap();
+    }
+    
+    #[test]
+    fn test_folded_chips_stay_in_the_pots() {
+        let (mut game, mut states) = seated_game(&[50, 200, 200]);
+        for (seat, bet) in [50, 150, 100].into_iter().enumerate() {
+            game.pot += states[seat].post_blind(bet);
+        }
+        // Seat 2 folds to seat 1's raise
+        states[2].has_folded = true;
+        
+        let pot_manager = PotManager::from_hand(&game, &states).unwrap();
+        assert_eq!(pot_manager.side_pots[0].amount, 150);
+        assert_eq!(pot_manager.side_pots[0].player_count, 2);
+        assert_eq!(pot_manager.side_pots[1].amount, 150);
+        assert_eq!(pot_manager.side_pots[1].player_count, 1);
+        assert_eq!(pot_manager.get_total_pot(), 300);
+    }
+}
@@ -115,6 +115,19 @@ pub fn validate_all_in(
     Ok(player_state.chip_stack)
 }
 
+/// Validate the hand and action sequence the client built the action against
+pub fn validate_action_sequence(
+    game: &Game,
+    hand_number: u64,
+    action_seq: u64,
+) -> Result<()> {
+    require!(
+        game.hand_number == hand_number && game.action_seq == action_seq,
+        PokerError::StaleAction
+    );
+    Ok(())
+}
+
 /// Validate game is in correct stage for betting
 pub fn validate_betting_stage(game: &Game) -> Result<()> {
     require!(
@@ -136,7 +149,7 @@ pub fn is_betting_round_complete(
     let mut acted_count = 0;
     let mut max_bet = 0u64;
     
-    for i in 0..game.player_count as usize {
+    for i in 0..game.seat_count() as usize {
         if !game.active_players[i] {
             continue;
         }
@@ -172,7 +185,7 @@ pub fn validate_action_timeout(
     let time_since_last_action = current_time - game.last_action_at;
     
     require!(
-        time_since_last_action < TURN_TIMEOUT,
+        time_since_last_action < game.turn_timeout,
         PokerError::InvalidAction
     );
     
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::game::state::Game;
 use crate::player::state::PlayerState;
 use crate::arcium::mpc_deal::{mpc_deal_card, DealParams, EncryptedCard};
-use crate::shared::{constants::*, PokerError};
+use crate::shared::PokerError;
 use crate::types::GameStage;
 
 /// Deal hole cards to all players
@@ -16,9 +16,9 @@ pub fn deal_hole_cards(
     );
     require!(game.deck_initialized, PokerError::DeckNotInitialized);
     
-    msg!("[DEALING] Dealing {} hole cards to {} players", HOLE_CARDS, game.player_count);
+    msg!("[DEALING] Dealing {} hole cards to {} players", game.variant.hole_cards(), game.player_count);
     
-    // Deal HOLE_CARDS (2) cards to each player
+    // Deal 2 (Hold'em) or 4 (Omaha) cards to each player
     for player_state in player_states.iter_mut() {
         if !player_state.has_cards {
             deal_cards_to_player(game, player_state)?;
@@ -37,7 +37,7 @@ fn deal_cards_to_player(
     msg!("[DEALING] Dealing to player at seat {}", player_state.seat_index);
     
     // Deal hole cards using Arcium MPC
-    for i in 0..HOLE_CARDS {
+    for i in 0..game.variant.hole_cards() {
         // Get next encrypted card index from deck
         let mut encrypted_deck = game.get_encrypted_deck()?;
         let card_index = encrypted_deck.get_next_encrypted_card()?;
@@ -97,5 +97,20 @@ pub fn reveal_community_cards(
         );
     }
     
+    // Run it twice: deal the same street again for the second board
+    if game.run_it_twice {
+        let first_index = game.community_cards_revealed - count;
+        for community_index in first_index..game.community_cards_revealed {
+            let card_index = encrypted_deck.get_next_encrypted_card()?;
+            game.second_board[community_index as usize] = card_index;
+            
+            msg!(
+                "[DEALING] Second board card {} revealed (index: {})",
+                community_index + 1,
+                card_index
+            );
+        }
+    }
+    
     Ok(())
 }
\ No newline at end of file
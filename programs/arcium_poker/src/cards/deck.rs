@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::types::{Suit, Rank};
-use crate::shared::constants::DECK_SIZE;
+use crate::types::{Suit, Rank, GameVariant};
+use crate::shared::constants::{DECK_SIZE, SHORT_DECK_SIZE, SHORT_DECK_LOWEST_RANK};
 
 /// Represents a playing card
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -57,6 +57,23 @@ impl Card {
         
         Ok(Card { suit, rank })
     }
+    
+    /// Create card from index (0-51), rejecting cards not in the variant's deck
+    /// Short deck uses the same indices with 2s through 5s removed
+    pub fn from_index_for_variant(index: u8, variant: GameVariant) -> Result<Self> {
+        let card = Self::from_index(index)?;
+        
+        if variant.deck_size() == SHORT_DECK_SIZE {
+            require!(card.is_short_deck_card(), crate::shared::PokerError::InvalidCardIndex);
+        }
+        
+        Ok(card)
+    }
+    
+    /// Check if card is part of the 36-card short deck (6 through Ace)
+    pub fn is_short_deck_card(&self) -> bool {
+        self.rank as u8 >= SHORT_DECK_LOWEST_RANK
+    }
 }
 
 /// Encrypted deck state (stored in Game account)
@@ -78,6 +95,9 @@ pub struct EncryptedDeck {
     
     /// Shuffle round/session ID from Arcium
     pub shuffle_session_id: [u8; 32],
+    
+    /// Number of cards in play (52, or 36 for short deck)
+    pub deck_size: u8,
 }
 
 impl Default for EncryptedDeck {
@@ -88,6 +108,7 @@ impl Default for EncryptedDeck {
             next_card_index: 0,
             cards_dealt: 0,
             shuffle_session_id: [0; 32],
+            deck_size: DECK_SIZE as u8,
         }
     }
 }
@@ -98,6 +119,7 @@ impl EncryptedDeck {
         encrypted_indices: [u8; DECK_SIZE],
         shuffle_commitment: [u8; 32],
         shuffle_session_id: [u8; 32],
+        deck_size: u8,
     ) -> Self {
         Self {
             encrypted_indices,
@@ -105,13 +127,14 @@ impl EncryptedDeck {
             next_card_index: 0,
             cards_dealt: 0,
             shuffle_session_id,
+            deck_size,
         }
     }
     
     /// Get next card index to deal (still encrypted)
     pub fn get_next_encrypted_card(&mut self) -> Result<u8> {
         require!(
-            self.next_card_index < DECK_SIZE as u8,
+            self.next_card_index < self.deck_size,
             crate::shared::PokerError::InvalidCardIndex
         );
         
@@ -130,7 +153,7 @@ impl EncryptedDeck {
     
     /// Check if deck has enough cards
     pub fn has_cards(&self, count: u8) -> bool {
-        self.next_card_index + count <= DECK_SIZE as u8
+        self.next_card_index + count <= self.deck_size
     }
 }
 
@@ -163,4 +186,29 @@ pub fn generate_standard_deck() -> [Card; DECK_SIZE] {
     }
     
     deck
-}
\ No newline at end of file
+}
+/// Card indices (0-51) making up the 36-card short deck, in deck order
+pub fn short_deck_indices() -> [u8; SHORT_DECK_SIZE] {
+    let mut indices = [0u8; SHORT_DECK_SIZE];
+    let mut index = 0;
+    
+    for suit_offset in [0u8, 13, 26, 39] {
+        for rank_val in SHORT_DECK_LOWEST_RANK..=14 {
+            indices[index] = suit_offset + rank_val - 2;
+            index += 1;
+        }
+    }
+    
+    indices
+}
+
+/// Generate 36-card short deck (unshuffled)
+pub fn generate_short_deck() -> Result<[Card; SHORT_DECK_SIZE]> {
+    let mut deck = [Card { suit: Suit::Hearts, rank: Rank::Six }; SHORT_DECK_SIZE];
+    
+    for (i, &card_index) in short_deck_indices().iter().enumerate() {
+        deck[i] = Card::from_index(card_index)?;
+    }
+    
+    Ok(deck)
+}
@@ -2,11 +2,13 @@ use anchor_lang::prelude::*;
 use super::deck::Card;
 use crate::types::{HandRank, Rank, Suit};
 use crate::shared::PokerError;
+use crate::shared::constants::{LOW_HAND_QUALIFIER, OMAHA_HOLE_CARDS};
 
 /// Evaluated hand with rank and kickers
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EvaluatedHand {
     pub rank: HandRank,
+    pub strength: u8,         // Category strength under the table's ranking rules
     pub primary_value: u8,    // Main card value (e.g., pair value, three of a kind value)
     pub secondary_value: u8,  // Secondary value (e.g., second pair in two pair)
     pub kickers: [u8; 5],     // Kicker cards for tie-breaking
@@ -16,6 +18,7 @@ impl EvaluatedHand {
     pub fn new(rank: HandRank, primary: u8, secondary: u8, kickers: [u8; 5]) -> Self {
         Self {
             rank,
+            strength: rank as u8,
             primary_value: primary,
             secondary_value: secondary,
             kickers,
@@ -31,8 +34,8 @@ impl PartialOrd for EvaluatedHand {
 
 impl Ord for EvaluatedHand {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // Compare hand rank first
-        match self.rank.cmp(&other.rank) {
+        // Compare hand category first (strength reflects short deck ordering)
+        match self.strength.cmp(&other.strength).then(self.rank.cmp(&other.rank)) {
             std::cmp::Ordering::Equal => {
                 // Same rank, compare primary value
                 match self.primary_value.cmp(&other.primary_value) {
@@ -54,15 +57,61 @@ impl Ord for EvaluatedHand {
     }
 }
 
+/// Ace-to-five low hand (ranks sorted high to low, ace counted as 1)
+/// A smaller value is a better low, e.g. 5-4-3-2-A beats 8-7-6-5-4
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LowHand {
+    pub ranks: [u8; 5],
+}
+
+/// Hand ranking rules for a table
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HandRules {
+    /// Short deck (6+): A-6-7-8-9 is the lowest straight and a flush beats a full house
+    pub short_deck: bool,
+    
+    /// Short deck option: three of a kind beats a straight
+    pub trips_beat_straights: bool,
+}
+
+impl HandRules {
+    /// Category strength of a hand rank under these rules
+    pub fn category_strength(&self, rank: HandRank) -> u8 {
+        if !self.short_deck {
+            return rank as u8;
+        }
+        
+        match rank {
+            HandRank::ThreeOfAKind if self.trips_beat_straights => HandRank::Straight as u8,
+            HandRank::Straight if self.trips_beat_straights => HandRank::ThreeOfAKind as u8,
+            HandRank::FullHouse => HandRank::Flush as u8,
+            HandRank::Flush => HandRank::FullHouse as u8,
+            other => other as u8,
+        }
+    }
+}
+
 /// Evaluate a 5-card poker hand
 pub fn evaluate_hand(cards: &[Card; 5]) -> Result<EvaluatedHand> {
+    evaluate_hand_with_rules(cards, &HandRules::default())
+}
+
+/// Evaluate a 5-card poker hand under the given ranking rules
+pub fn evaluate_hand_with_rules(cards: &[Card; 5], rules: &HandRules) -> Result<EvaluatedHand> {
+    let mut hand = classify_hand(cards, rules.short_deck)?;
+    hand.strength = rules.category_strength(hand.rank);
+    Ok(hand)
+}
+
+/// Classify a 5-card hand into its rank, primary/secondary values and kickers
+fn classify_hand(cards: &[Card; 5], short_deck: bool) -> Result<EvaluatedHand> {
     require!(cards.len() == 5, PokerError::InvalidCardIndex);
     
     // Check for flush
     let is_flush = is_flush(cards);
     
     // Check for straight
-    let straight_high = check_straight(cards);
+    let straight_high = check_straight(cards, short_deck);
     let is_straight = straight_high.is_some();
     
     // Get rank counts
@@ -122,6 +171,15 @@ pub fn evaluate_hand(cards: &[Card; 5]) -> Result<EvaluatedHand> {
 
 /// Evaluate best 5-card hand from 7 cards (2 hole + 5 community)
 pub fn evaluate_best_hand(hole_cards: &[Card; 2], community_cards: &[Card; 5]) -> Result<EvaluatedHand> {
+    evaluate_best_hand_with_rules(hole_cards, community_cards, &HandRules::default())
+}
+
+/// Evaluate best 5-card hand from 7 cards under the given ranking rules
+pub fn evaluate_best_hand_with_rules(
+    hole_cards: &[Card; 2],
+    community_cards: &[Card; 5],
+    rules: &HandRules,
+) -> Result<EvaluatedHand> {
     let mut all_cards = Vec::with_capacity(7);
     all_cards.extend_from_slice(hole_cards);
     all_cards.extend_from_slice(community_cards);
@@ -142,7 +200,7 @@ pub fn evaluate_best_hand(hole_cards: &[Card; 2], community_cards: &[Card; 5]) -
                 }
             }
             
-            let evaluated = evaluate_hand(&hand)?;
+            let evaluated = evaluate_hand_with_rules(&hand, rules)?;
             
             if best_hand.is_none() || evaluated > best_hand.unwrap() {
                 best_hand = Some(evaluated);
@@ -153,6 +211,129 @@ pub fn evaluate_best_hand(hole_cards: &[Card; 2], community_cards: &[Card; 5]) -
     best_hand.ok_or(PokerError::InvalidCardIndex.into())
 }
 
+/// Check whether both hole cards are needed to make the best hand
+/// True only if every hand using at most one hole card is strictly worse
+pub fn plays_both_hole_cards(
+    hole_cards: &[Card; 2],
+    community_cards: &[Card; 5],
+    rules: &HandRules,
+) -> Result<bool> {
+    let best = evaluate_best_hand_with_rules(hole_cards, community_cards, rules)?;
+    
+    // Playing the board
+    let mut best_without = evaluate_hand_with_rules(community_cards, rules)?;
+    
+    // One hole card plus four of the board
+    for hole in hole_cards.iter() {
+        for skip in 0..5 {
+            let mut hand = [*hole; 5];
+            let mut idx = 1;
+            for (k, board_card) in community_cards.iter().enumerate() {
+                if k != skip {
+                    hand[idx] = *board_card;
+                    idx += 1;
+                }
+            }
+            
+            let evaluated = evaluate_hand_with_rules(&hand, rules)?;
+            if evaluated > best_without {
+                best_without = evaluated;
+            }
+        }
+    }
+    
+    Ok(best > best_without)
+}
+
+/// Evaluate a 5-card hand as an 8-or-better low
+/// Straights and flushes do not count against a low; pairs disqualify it
+pub fn evaluate_low_hand(cards: &[Card; 5]) -> Option<LowHand> {
+    let mut ranks = [0u8; 5];
+    for (i, card) in cards.iter().enumerate() {
+        ranks[i] = low_rank_value(card.rank);
+    }
+    
+    if ranks.iter().any(|&r| r > LOW_HAND_QUALIFIER) {
+        return None;
+    }
+    
+    ranks.sort_unstable();
+    ranks.reverse();
+    
+    // All five ranks must be distinct
+    if ranks.windows(2).any(|pair| pair[0] == pair[1]) {
+        return None;
+    }
+    
+    Some(LowHand { ranks })
+}
+
+/// Evaluate best Omaha high hand (exactly 2 hole cards + 3 community cards)
+pub fn evaluate_omaha_high(
+    hole_cards: &[Card; OMAHA_HOLE_CARDS],
+    community_cards: &[Card; 5],
+) -> Result<EvaluatedHand> {
+    let mut best_hand: Option<EvaluatedHand> = None;
+    
+    for hand in omaha_combinations(hole_cards, community_cards) {
+        let evaluated = evaluate_hand(&hand)?;
+        
+        if best_hand.is_none() || evaluated > best_hand.unwrap() {
+            best_hand = Some(evaluated);
+        }
+    }
+    
+    best_hand.ok_or(PokerError::InvalidCardIndex.into())
+}
+
+/// Evaluate best Omaha 8-or-better low (exactly 2 hole cards + 3 community cards)
+/// Returns None if the player has no qualifying low
+pub fn evaluate_omaha_low(
+    hole_cards: &[Card; OMAHA_HOLE_CARDS],
+    community_cards: &[Card; 5],
+) -> Option<LowHand> {
+    omaha_combinations(hole_cards, community_cards)
+        .iter()
+        .filter_map(evaluate_low_hand)
+        .min()
+}
+
+/// Generate all 60 Omaha hands (6 hole pairs x 10 board triples)
+fn omaha_combinations(
+    hole_cards: &[Card; OMAHA_HOLE_CARDS],
+    community_cards: &[Card; 5],
+) -> Vec<[Card; 5]> {
+    let mut hands = Vec::with_capacity(60);
+    
+    for h1 in 0..OMAHA_HOLE_CARDS {
+        for h2 in (h1 + 1)..OMAHA_HOLE_CARDS {
+            for b1 in 0..5 {
+                for b2 in (b1 + 1)..5 {
+                    for b3 in (b2 + 1)..5 {
+                        hands.push([
+                            hole_cards[h1],
+                            hole_cards[h2],
+                            community_cards[b1],
+                            community_cards[b2],
+                            community_cards[b3],
+                        ]);
+                    }
+                }
+            }
+        }
+    }
+    
+    hands
+}
+
+/// Rank value for low hands (ace plays low)
+fn low_rank_value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 1,
+        other => other as u8,
+    }
+}
+
 /// Check if all cards are same suit
 fn is_flush(cards: &[Card]) -> bool {
     let first_suit = cards[0].suit;
@@ -160,7 +341,7 @@ fn is_flush(cards: &[Card]) -> bool {
 }
 
 /// Check for straight, returns high card if straight
-fn check_straight(cards: &[Card]) -> Option<u8> {
+fn check_straight(cards: &[Card], short_deck: bool) -> Option<u8> {
     let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank as u8).collect();
     ranks.sort_unstable();
     ranks.reverse();
@@ -179,6 +360,11 @@ fn check_straight(cards: &[Card]) -> Option<u8> {
         return Some(5); // 5-high straight
     }
     
+    // Short deck: ace plays low below the 6 (A-6-7-8-9)
+    if short_deck && ranks[0] == 14 && ranks[1] == 9 && ranks[2] == 8 && ranks[3] == 7 && ranks[4] == 6 {
+        return Some(9); // 9-high straight
+    }
+    
     None
 }
 
@@ -308,4 +494,188 @@ fn get_kickers(cards: &[Card], exclude_ranks: &[u8]) -> [u8; 5] {
     }
     
     kickers
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(suit, rank)
+    }
+    
+    #[test]
+    fn test_low_hand_qualification() {
+        let wheel = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Three, Suit::Hearts),
+            card(Rank::Four, Suit::Hearts),
+            card(Rank::Five, Suit::Hearts),
+        ];
+        assert_eq!(evaluate_low_hand(&wheel).unwrap().ranks, [5, 4, 3, 2, 1]);
+        
+        // Nine-high does not qualify
+        let nine_low = [
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Three, Suit::Hearts),
+            card(Rank::Four, Suit::Spades),
+            card(Rank::Five, Suit::Hearts),
+        ];
+        assert!(evaluate_low_hand(&nine_low).is_none());
+        
+        // Paired hands do not qualify
+        let paired = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Three, Suit::Hearts),
+            card(Rank::Four, Suit::Spades),
+            card(Rank::Five, Suit::Hearts),
+        ];
+        assert!(evaluate_low_hand(&paired).is_none());
+        
+        // 8-5-4-3-2 loses to 7-6-5-4-3
+        let eight_low = evaluate_low_hand(&[
+            card(Rank::Eight, Suit::Hearts),
+            card(Rank::Five, Suit::Clubs),
+            card(Rank::Four, Suit::Hearts),
+            card(Rank::Three, Suit::Spades),
+            card(Rank::Two, Suit::Hearts),
+        ]).unwrap();
+        let seven_low = evaluate_low_hand(&[
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Six, Suit::Clubs),
+            card(Rank::Five, Suit::Hearts),
+            card(Rank::Four, Suit::Spades),
+            card(Rank::Three, Suit::Hearts),
+        ]).unwrap();
+        assert!(seven_low < eight_low);
+    }
+    
+    #[test]
+    fn test_omaha_uses_exactly_two_hole_cards() {
+        // Four hearts in hand but only one heart on board: no flush
+        let hole = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Queen, Suit::Hearts),
+            card(Rank::Jack, Suit::Hearts),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Seven, Suit::Clubs),
+            card(Rank::Nine, Suit::Spades),
+            card(Rank::Four, Suit::Diamonds),
+            card(Rank::Three, Suit::Clubs),
+        ];
+        let high = evaluate_omaha_high(&hole, &board).unwrap();
+        assert_eq!(high.rank, HandRank::HighCard);
+        
+        // Board four-flush with a single heart in hand: no flush either
+        let hole = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Clubs),
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Jack, Suit::Diamonds),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Four, Suit::Hearts),
+            card(Rank::Three, Suit::Clubs),
+        ];
+        let high = evaluate_omaha_high(&hole, &board).unwrap();
+        assert_ne!(high.rank, HandRank::Flush);
+        
+        // Low needs two qualifying hole cards
+        assert!(evaluate_omaha_low(&hole, &board).is_none());
+    }
+    
+    #[test]
+    fn test_short_deck_rankings() {
+        let short_deck = HandRules { short_deck: true, trips_beat_straights: false };
+        
+        // A-6-7-8-9 is a straight only in short deck
+        let wheel = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Six, Suit::Clubs),
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::Eight, Suit::Diamonds),
+            card(Rank::Nine, Suit::Hearts),
+        ];
+        assert_eq!(evaluate_hand(&wheel).unwrap().rank, HandRank::HighCard);
+        let straight = evaluate_hand_with_rules(&wheel, &short_deck).unwrap();
+        assert_eq!(straight.rank, HandRank::Straight);
+        assert_eq!(straight.primary_value, 9);
+        
+        // Flush beats full house
+        let flush = [
+            card(Rank::Six, Suit::Hearts),
+            card(Rank::Eight, Suit::Hearts),
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Jack, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let full_house = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::King, Suit::Clubs),
+        ];
+        assert!(evaluate_hand(&full_house).unwrap() > evaluate_hand(&flush).unwrap());
+        assert!(
+            evaluate_hand_with_rules(&flush, &short_deck).unwrap()
+                > evaluate_hand_with_rules(&full_house, &short_deck).unwrap()
+        );
+        
+        // Optional rule: three of a kind beats a straight
+        let trips = [
+            card(Rank::Six, Suit::Hearts),
+            card(Rank::Six, Suit::Clubs),
+            card(Rank::Six, Suit::Spades),
+            card(Rank::Nine, Suit::Diamonds),
+            card(Rank::Queen, Suit::Clubs),
+        ];
+        assert!(straight > evaluate_hand_with_rules(&trips, &short_deck).unwrap());
+        
+        let trips_rule = HandRules { short_deck: true, trips_beat_straights: true };
+        assert!(
+            evaluate_hand_with_rules(&trips, &trips_rule).unwrap()
+                > evaluate_hand_with_rules(&wheel, &trips_rule).unwrap()
+        );
+    }
+    
+    #[test]
+    fn test_plays_both_hole_cards() {
+        let rules = HandRules::default();
+        let board = [
+            card(Rank::Jack, Suit::Clubs),
+            card(Rank::Jack, Suit::Diamonds),
+            card(Rank::Four, Suit::Spades),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+        ];
+        
+        // Pocket jacks make quads with both hole cards
+        let pocket_jacks = [card(Rank::Jack, Suit::Hearts), card(Rank::Jack, Suit::Spades)];
+        assert!(plays_both_hole_cards(&pocket_jacks, &board, &rules).unwrap());
+        
+        // One jack makes trips; the other hole card is only a kicker
+        let one_jack = [card(Rank::Jack, Suit::Hearts), card(Rank::Three, Suit::Spades)];
+        assert!(!plays_both_hole_cards(&one_jack, &board, &rules).unwrap());
+        
+        // Quads on the board play without any hole card
+        let quad_board = [
+            card(Rank::Jack, Suit::Clubs),
+            card(Rank::Jack, Suit::Diamonds),
+            card(Rank::Jack, Suit::Hearts),
+            card(Rank::Jack, Suit::Spades),
+            card(Rank::Ace, Suit::Clubs),
+        ];
+        let low_cards = [card(Rank::Two, Suit::Hearts), card(Rank::Three, Suit::Spades)];
+        assert!(!plays_both_hole_cards(&low_cards, &quad_board, &rules).unwrap());
+    }
+}
@@ -5,6 +5,18 @@ pub mod reveal;
 pub mod evaluator;
 
 // Export specific types only, not glob
-pub use deck::{Card, EncryptedDeck, generate_standard_deck};
+pub use deck::{Card, EncryptedDeck, generate_standard_deck, generate_short_deck, short_deck_indices};
 pub use dealing::{deal_hole_cards, reveal_community_cards};
-pub use evaluator::{EvaluatedHand, evaluate_hand, evaluate_best_hand};
\ No newline at end of file
+pub use evaluator::{
+    EvaluatedHand,
+    HandRules,
+    LowHand,
+    evaluate_hand,
+    evaluate_hand_with_rules,
+    evaluate_best_hand,
+    evaluate_best_hand_with_rules,
+    plays_both_hole_cards,
+    evaluate_low_hand,
+    evaluate_omaha_high,
+    evaluate_omaha_low,
+};
\ No newline at end of file
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use super::state::Game;
+use crate::player::rewards::accrue_before_close;
+use crate::player::state::{Bankroll, PlayerState};
+use crate::shared::PokerError;
+use crate::token::close_token_escrow;
+
+/// Close a dead table and reclaim its rent
+///
+/// Every stack must already be cashed out, the pot settled, rake swept and
+/// any jackpot closed. Each PlayerState is closed with its rent returned to
+/// the player who paid it, then the token escrow and the Game are closed to
+/// the authority. The close is refused if any lamports beyond rent remain on
+/// the Game or any tokens remain in the escrow, since they are unaccounted
+/// for; `sweep_surplus` moves them out first.
+/// Remaining accounts, for every PlayerState of this game: the PlayerState
+/// and the player's wallet, followed by the player's bankroll when the seat
+/// was bought from it, then the player's rewards PDA (and their referrer's
+/// rewards when an affiliate share is due) when rake is still pending.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, crate::CloseTable<'info>>) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let game_key = game.key();
+    
+    validate_table_closable(game)?;
+    
+    let game_info = game.to_account_info();
+    let escrow_tokens = match ctx.accounts.escrow_token_account.as_ref() {
+        Some(escrow) if game.is_token_table() => escrow.amount,
+        _ => 0,
+    };
+    validate_escrow_empty(
+        escrow_tokens,
+        game_info.lamports(),
+        Rent::get()?.minimum_balance(game_info.data_len()),
+    )?;
+    
+    // Close every PlayerState, counting the seats still occupied
+    let mut seats_closed = 0u8;
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(state_info) = accounts.next() {
+        let mut player_state = PlayerState::load(state_info, &game_key)?;
+        let wallet = accounts.next().ok_or(PokerError::PlayerNotInGame)?;
+        require_keys_eq!(wallet.key(), player_state.player, PokerError::PlayerNotInGame);
+        require!(
+            player_state.chip_stack == 0 && player_state.rebuy_reserve == 0,
+            PokerError::TableNotEmpty
+        );
+        
+        // The bankroll stops counting this table
+        if player_state.funded_by_bankroll {
+            let bankroll_info = accounts.next().ok_or(PokerError::InvalidBankroll)?;
+            release_bankroll_seat(bankroll_info, &player_state.player)?;
+        }
+        
+        if player_state.pending_rake > 0 {
+            accrue_before_close(
+                game,
+                &mut player_state,
+                accounts.next(),
+                accounts.by_ref(),
+                ctx.accounts.treasury_ledger.as_deref_mut(),
+            )?;
+        }
+        
+        let seat = player_state.seat_index;
+        if game.is_seat_occupied(seat) && game.players[seat as usize] == player_state.player {
+            seats_closed += 1;
+        }
+        
+        close_player_state(state_info, wallet)?;
+        
+        msg!("[CLOSE] Closed seat {} for {}", seat, player_state.player);
+    }
+    require!(seats_closed == game.player_count, PokerError::PlayerNotInGame);
+    
+    // Token tables: the empty escrow is closed
+    if game.is_token_table() {
+        let escrow = ctx.accounts.escrow_token_account.as_ref().ok_or(PokerError::InvalidTokenAccounts)?;
+        let escrow_authority = ctx.accounts.escrow_authority.as_ref().ok_or(PokerError::InvalidTokenAccounts)?;
+        let token_program = ctx.accounts.token_program.as_ref().ok_or(PokerError::InvalidTokenAccounts)?;
+        require_keys_eq!(escrow.key(), game.token_escrow, PokerError::InvalidTokenAccounts);
+        
+        close_token_escrow(
+            escrow,
+            &ctx.accounts.authority.to_account_info(),
+            escrow_authority,
+            token_program,
+            game.escrow_bump,
+            game_key,
+        )?;
+    }
+    
+    msg!("[CLOSE] Game {} closed, rent returned to {}", game.game_id, game.authority);
+    
+    // The Game account itself is closed to the authority on exit
+    Ok(())
+}
+
+/// Nothing may be in play or owed when the table closes
+pub(crate) fn validate_table_closable(game: &Game) -> Result<()> {
+    require!(!game.is_hand_in_progress(), PokerError::TableNotEmpty);
+    require!(
+        game.pot == 0 && game.collected_rake == 0 && !game.has_jackpot,
+        PokerError::TableNotEmpty
+    );
+    require!(game.escrowed_chips == 0, PokerError::EscrowImbalance);
+    Ok(())
+}
+
+/// Nothing beyond rent may be left behind when the table closes
+/// Escrow tokens or Game lamports above the rent-exempt minimum are value
+/// the table cannot account for, and must be swept with `sweep_surplus`
+pub(crate) fn validate_escrow_empty(escrow_tokens: u64, game_lamports: u64, rent_exempt: u64) -> Result<()> {
+    require!(escrow_tokens == 0, PokerError::EscrowImbalance);
+    require!(game_lamports <= rent_exempt, PokerError::EscrowImbalance);
+    Ok(())
+}
+
+/// Decrement the seat count of a player's bankroll
+fn release_bankroll_seat(bankroll_info: &AccountInfo, owner: &Pubkey) -> Result<()> {
+    require!(bankroll_info.owner == &crate::ID, PokerError::InvalidBankroll);
+    
+    let mut bankroll = {
+        let data = bankroll_info.try_borrow_data()?;
+        Bankroll::try_deserialize(&mut &data[..])?
+    };
+    require_keys_eq!(bankroll.owner, *owner, PokerError::InvalidBankroll);
+    
+    bankroll.seated_tables = bankroll.seated_tables.saturating_sub(1);
+    
+    let mut data = bankroll_info.try_borrow_mut_data()?;
+    let mut writer = &mut data[..];
+    bankroll.try_serialize(&mut writer)?;
+    Ok(())
+}
+
+/// Close a PlayerState account, returning its rent to the player
+pub(crate) fn close_player_state<'info>(
+    state_info: &AccountInfo<'info>,
+    wallet: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = state_info.lamports();
+    **state_info.try_borrow_mut_lamports()? = 0;
+    **wallet.try_borrow_mut_lamports()? += lamports;
+    
+    state_info.assign(&system_program::ID);
+    state_info.resize(0)?;
+    Ok(())
+}
+
+// Uses the CloseTable accounts struct in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::test_game;
+    use crate::types::GameStage;
+    
+    #[test]
+    fn test_owed_chips_block_close() {
+        let mut game = test_game();
+        assert!(validate_table_closable(&game).is_ok());
+        
+        // Chips still owed to players or the house keep the table open
+        game.escrowed_chips = 50;
+        assert!(validate_table_closable(&game).is_err());
+        game.escrowed_chips = 0;
+        
+        game.collected_rake = 5;
+        assert!(validate_table_closable(&game).is_err());
+        game.collected_rake = 0;
+        
+        game.stage = GameStage::Flop;
+        assert!(validate_table_closable(&game).is_err());
+    }
+    
+    #[test]
+    fn test_unaccounted_value_blocks_close() {
+        validate_escrow_empty(0, 1_000, 1_000).unwrap();
+        
+        // Tokens left in the escrow or lamports above rent must be swept first
+        assert!(validate_escrow_empty(1, 1_000, 1_000).is_err());
+        assert!(validate_escrow_empty(0, 1_001, 1_000).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use crate::types::{AnteType, GameStage, GameVariant, StraddleType};
+use crate::shared::PokerError;
+use crate::advanced::rake::{RakeConfig, validate_rake_config};
+
+/// Table settings to update (None leaves the current value unchanged)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct TableConfigParams {
+    /// Poker variant dealt at the table
+    pub variant: Option<GameVariant>,
+    
+    /// Short deck option: three of a kind beats a straight
+    pub trips_beat_straights: Option<bool>,
+    
+    /// Ante amount (per player for standard antes, total for big blind ante)
+    pub ante: Option<u64>,
+    
+    /// Ante format
+    pub ante_type: Option<AnteType>,
+    
+    /// Voluntary straddle allowed at the table
+    pub straddle_type: Option<StraddleType>,
+    
+    /// Per-player ante for bomb pot hands
+    pub bomb_pot_ante: Option<u64>,
+    
+    /// Seconds to act before the time bank is used
+    pub turn_timeout: Option<i64>,
+    
+    /// Time bank each player starts with and refills up to
+    pub time_bank_max: Option<i64>,
+    
+    /// Seconds added back to each time bank per hand
+    pub time_bank_refill: Option<i64>,
+    
+    /// Bounty paid from collected rake for cranking a timed-out player
+    pub crank_fee: Option<u64>,
+    
+    /// Refuse token deposits from mints with a freeze authority or unsafe extensions
+    pub reject_unsafe_mint: Option<bool>,
+    
+    /// Rake policy (lifetime rake totals are kept)
+    pub rake: Option<RakeConfig>,
+    
+    /// Fee on partial cash-outs (basis points)
+    pub withdrawal_fee_bps: Option<u16>,
+    
+    /// Seconds after leaving during which a rejoin must bring back the previous stack (0 = off)
+    pub rathole_window: Option<i64>,
+}
+
+/// Update table settings before the first hand or between hands
+pub fn handler(
+    ctx: Context<crate::ConfigureTable>,
+    params: TableConfigParams,
+) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    
+    // Settings can only change while no hand is in progress
+    require!(
+        game.stage == GameStage::Waiting || game.stage == GameStage::Finished,
+        PokerError::GameAlreadyStarted
+    );
+    
+    if let Some(variant) = params.variant {
+        game.variant = variant;
+        msg!("[CONFIG] Variant set to {:?}", variant);
+    }
+    
+    if let Some(trips_beat_straights) = params.trips_beat_straights {
+        game.trips_beat_straights = trips_beat_straights;
+        msg!("[CONFIG] Trips beat straights: {}", trips_beat_straights);
+    }
+    
+    if let Some(ante) = params.ante {
+        game.ante = ante;
+    }
+    
+    if let Some(ante_type) = params.ante_type {
+        game.ante_type = ante_type;
+    }
+    
+    // An ante format needs a non-zero ante
+    require!(
+        game.ante_type == AnteType::None || game.ante > 0,
+        PokerError::InvalidGameConfig
+    );
+    
+    if params.ante.is_some() || params.ante_type.is_some() {
+        msg!("[CONFIG] Ante set to {} ({:?})", game.ante, game.ante_type);
+    }
+    
+    if let Some(straddle_type) = params.straddle_type {
+        game.straddle_type = straddle_type;
+        msg!("[CONFIG] Straddle set to {:?}", straddle_type);
+    }
+    
+    if let Some(bomb_pot_ante) = params.bomb_pot_ante {
+        game.bomb_pot_ante = bomb_pot_ante;
+        msg!("[CONFIG] Bomb pot ante set to {}", bomb_pot_ante);
+    }
+    
+    if let Some(turn_timeout) = params.turn_timeout {
+        require!(turn_timeout > 0, PokerError::InvalidGameConfig);
+        game.turn_timeout = turn_timeout;
+    }
+    
+    if let Some(time_bank_max) = params.time_bank_max {
+        require!(time_bank_max >= 0, PokerError::InvalidGameConfig);
+        game.time_bank_max = time_bank_max;
+    }
+    
+    if let Some(time_bank_refill) = params.time_bank_refill {
+        require!(time_bank_refill >= 0, PokerError::InvalidGameConfig);
+        game.time_bank_refill = time_bank_refill;
+    }
+    
+    if params.turn_timeout.is_some() || params.time_bank_max.is_some() || params.time_bank_refill.is_some() {
+        msg!(
+            "[CONFIG] Action clock: {}s, time bank {}s (+{}s per hand)",
+            game.turn_timeout,
+            game.time_bank_max,
+            game.time_bank_refill
+        );
+    }
+    
+    if let Some(crank_fee) = params.crank_fee {
+        game.crank_fee = crank_fee;
+        msg!("[CONFIG] Crank fee set to {}", crank_fee);
+    }
+    
+    if let Some(reject_unsafe_mint) = params.reject_unsafe_mint {
+        game.reject_unsafe_mint = reject_unsafe_mint;
+        msg!("[CONFIG] Reject unsafe mints: {}", reject_unsafe_mint);
+    }
+    
+    if let Some(rake) = params.rake {
+        if rake.rake_percentage > 0 {
+            validate_rake_config(&rake)?;
+        }
+        
+        game.rake = RakeConfig {
+            total_rake_collected: game.rake.total_rake_collected,
+            hands_raked: game.rake.hands_raked,
+            ..rake
+        };
+        
+        msg!(
+            "[CONFIG] Rake set to {} bps (cap {}, no flop no drop: {})",
+            rake.rake_percentage,
+            rake.rake_cap,
+            rake.no_flop_no_drop
+        );
+    }
+    
+    if let Some(withdrawal_fee_bps) = params.withdrawal_fee_bps {
+        require!(withdrawal_fee_bps <= 10_000, PokerError::InvalidGameConfig);
+        game.withdrawal_fee_bps = withdrawal_fee_bps;
+        msg!("[CONFIG] Withdrawal fee set to {} bps", withdrawal_fee_bps);
+    }
+    
+    if let Some(rathole_window) = params.rathole_window {
+        require!(rathole_window >= 0, PokerError::InvalidGameConfig);
+        game.rathole_window = rathole_window;
+        msg!("[CONFIG] Ratholing window set to {}s", rathole_window);
+    }
+    
+    msg!("Game {} configuration updated", game.game_id);
+    
+    Ok(())
+}
+
+/// Schedule the next hand as a bomb pot (can be called mid-hand)
+pub fn schedule_bomb_pot(ctx: Context<crate::ConfigureTable>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    
+    require!(game.bomb_pot_ante > 0, PokerError::InvalidGameConfig);
+    
+    game.bomb_pot_scheduled = true;
+    
+    msg!("[CONFIG] Bomb pot scheduled for next hand (ante {})", game.bomb_pot_ante);
+    
+    Ok(())
+}
+
+// ConfigureTable struct lives in lib.rs at crate root (required by Anchor)
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use super::flow::handle_player_timeout;
+use super::state::Game;
+use crate::token::{TokenRoute, payout_chips};
+use crate::security::validate_escrow_invariants;
+use crate::advanced::calculate_rewards;
+
+/// Check-fold a player whose turn clock and time bank have run out
+/// 
+/// Any signer can crank a stalled table. The cranker is paid the table's
+/// crank fee out of rake collected in the game escrow, when there is any.
+pub fn handler(ctx: Context<crate::CrankTimeout>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    
+    handle_player_timeout(game, &mut ctx.accounts.player_state)?;
+    
+    let bounty = take_crank_bounty(game);
+    if bounty > 0 {
+        // Pay from game escrow (lamports, or tokens on a token table)
+        let route = TokenRoute::for_game(
+            game,
+            ctx.accounts.player_token_account.as_ref(),
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.token_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        payout_chips(
+            game,
+            &ctx.accounts.cranker.to_account_info(),
+            ctx.accounts.escrow_authority.as_ref().map(|authority| authority.as_ref()),
+            route,
+            bounty,
+        )?;
+        validate_escrow_invariants(
+            game,
+            ctx.remaining_accounts,
+            Some(&ctx.accounts.player_state),
+            ctx.accounts.jackpot.as_ref(),
+            ctx.accounts.escrow_token_account.as_ref(),
+        )?;
+        
+        msg!("[CRANK] Paid {} to cranker {}", bounty, ctx.accounts.cranker.key());
+    }
+    
+    Ok(())
+}
+
+/// Take the crank fee out of the house share of collected rake
+/// The bounty is capped by the rake, so player chips are never touched, and
+/// leaves the rakeback and affiliate shares owed on it to reach the treasury
+pub(crate) fn take_crank_bounty(game: &mut Game) -> u64 {
+    let (rakeback, affiliate) = calculate_rewards(game.collected_rake, &game.rake, true);
+    let house_share = game.collected_rake.saturating_sub(rakeback + affiliate);
+    let bounty = game.crank_fee.min(house_share);
+    game.collected_rake -= bounty;
+    bounty
+}
+
+// Uses the CrankTimeout accounts struct in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::test_game;
+    
+    #[test]
+    fn test_crank_bounty_capped_by_rake() {
+        let mut game = test_game();
+        game.crank_fee = 50;
+        
+        // No rake, no bounty
+        assert_eq!(take_crank_bounty(&mut game), 0);
+        
+        game.collected_rake = 120;
+        assert_eq!(take_crank_bounty(&mut game), 50);
+        assert_eq!(game.collected_rake, 70);
+        
+        // Only what is left of the rake
+        game.collected_rake = 30;
+        assert_eq!(take_crank_bounty(&mut game), 30);
+        assert_eq!(game.collected_rake, 0);
+        
+        // Cranking is free when the table sets no fee
+        game.crank_fee = 0;
+        game.collected_rake = 100;
+        assert_eq!(take_crank_bounty(&mut game), 0);
+        assert_eq!(game.collected_rake, 100);
+    }
+    
+    #[test]
+    fn test_crank_bounty_leaves_rewards_share() {
+        let mut game = test_game();
+        game.crank_fee = 50;
+        game.rake.rakeback_percentage = 2_000; // 20%
+        game.rake.affiliate_percentage = 1_000; // 10%
+        
+        // 30 of the 100 rake may be owed back to players
+        game.collected_rake = 100;
+        assert_eq!(take_crank_bounty(&mut game), 50);
+        assert_eq!(game.collected_rake, 50);
+        
+        game.collected_rake = 40;
+        assert_eq!(take_crank_bounty(&mut game), 28);
+        assert_eq!(game.collected_rake, 12);
+    }
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 use super::state::Game;
 use crate::player::state::PlayerState;
-use crate::types::GameStage;
-use crate::shared::{PokerError, constants::*};
+use crate::types::{GameStage, PlayerStatus, StraddleType};
+use crate::shared::{PokerError, constants::*, utils::find_next_active_player};
 use crate::cards::dealing::reveal_community_cards;
-use crate::betting::is_betting_round_complete;
+use crate::betting::{is_betting_round_complete, validate_betting_stage};
+use crate::betting::instruction::advance_to_next_player_or_stage;
 
 /// Advance game to next stage (PreFlop -> Flop -> Turn -> River -> Showdown)
 /// Note: Caller should verify betting round is complete before calling this
@@ -37,6 +38,7 @@ pub fn advance_game_stage(
     };
     
     game.stage = next_stage;
+    game.action_seq += 1;
     
     // Reset betting state for new round
     reset_betting_round(game)?;
@@ -89,22 +91,20 @@ pub fn reset_betting_round(game: &mut Game) -> Result<()> {
 
 /// Get first player to act in a betting round
 pub fn get_first_player_for_round(game: &Game) -> u8 {
-    // In pre-flop, first to act is after big blind (dealer + 3)
+    // In pre-flop, first to act is after big blind, or after the straddler
     // In post-flop rounds, first to act is after dealer (dealer + 1)
-    let offset = if game.stage == GameStage::PreFlop {
-        3 // After big blind
-    } else {
-        1 // After dealer
+    let mut first_player = match (game.stage, game.straddle_seat) {
+        (GameStage::PreFlop, Some(straddle_seat)) => (straddle_seat + 1) % game.seat_count(),
+        (GameStage::PreFlop, None) => next_active_seat(game, get_big_blind_position(game)),
+        _ => (game.dealer_position + 1) % game.seat_count(), // After dealer
     };
     
-    let mut first_player = (game.dealer_position + offset) % game.player_count;
-    
     // Find first active player
-    for _ in 0..game.player_count {
+    for _ in 0..game.seat_count() {
         if game.active_players[first_player as usize] {
             return first_player;
         }
-        first_player = (first_player + 1) % game.player_count;
+        first_player = (first_player + 1) % game.seat_count();
     }
     
     // Fallback to dealer if no active players found
@@ -116,15 +116,15 @@ pub fn rotate_dealer_button(game: &mut Game) -> Result<()> {
     let old_dealer = game.dealer_position;
     
     // Find next active player
-    let mut next_dealer = (old_dealer + 1) % game.player_count;
+    let mut next_dealer = (old_dealer + 1) % game.seat_count();
     let mut found = false;
     
-    for _ in 0..game.player_count {
+    for _ in 0..game.seat_count() {
         if game.active_players[next_dealer as usize] {
             found = true;
             break;
         }
-        next_dealer = (next_dealer + 1) % game.player_count;
+        next_dealer = (next_dealer + 1) % game.seat_count();
     }
     
     require!(found, PokerError::NotEnoughPlayers);
@@ -142,46 +142,102 @@ pub fn rotate_dealer_button(game: &mut Game) -> Result<()> {
 
 /// Get small blind position
 pub fn get_small_blind_position(game: &Game) -> u8 {
-    // Small blind is dealer + 1 (or dealer in heads-up)
-    if game.player_count == 2 {
+    // Small blind is the next active seat after the dealer (or dealer in heads-up)
+    if active_player_count(game) == 2 {
         game.dealer_position
     } else {
-        (game.dealer_position + 1) % game.player_count
+        next_active_seat(game, game.dealer_position)
     }
 }
 
 /// Get big blind position
 pub fn get_big_blind_position(game: &Game) -> u8 {
-    // Big blind is dealer + 2 (or non-dealer in heads-up)
-    if game.player_count == 2 {
-        (game.dealer_position + 1) % game.player_count
-    } else {
-        (game.dealer_position + 2) % game.player_count
+    // Big blind is the next active seat after the small blind
+    next_active_seat(game, get_small_blind_position(game))
+}
+
+/// Next active seat after the given seat (players sitting out are skipped)
+fn next_active_seat(game: &Game, seat: u8) -> u8 {
+    find_next_active_player(
+        seat as usize,
+        &game.active_players,
+        game.seat_count() as usize,
+    )
+    .map_or(seat, |next| next as u8)
+}
+
+/// Number of players dealt into the current hand
+fn active_player_count(game: &Game) -> usize {
+    game.active_players[..game.seat_count() as usize]
+        .iter()
+        .filter(|&&active| active)
+        .count()
+}
+
+/// Get straddle position for the table's straddle type
+pub fn get_straddle_position(game: &Game) -> Option<u8> {
+    // A straddle needs a seat other than the blinds
+    if active_player_count(game) < 3 {
+        return None;
+    }
+    
+    match game.straddle_type {
+        StraddleType::None => None,
+        StraddleType::UnderTheGun => Some(next_active_seat(game, get_big_blind_position(game))),
+        StraddleType::Button => Some(game.dealer_position),
     }
 }
 
 /// Check if player's turn has timed out
-pub fn check_turn_timeout(game: &Game) -> Result<bool> {
-    let current_time = Clock::get()?.unix_timestamp;
-    let time_since_last_action = current_time - game.last_action_at;
-    
-    Ok(time_since_last_action >= TURN_TIMEOUT)
+/// The table's turn clock runs first, then the player's time bank
+pub fn check_turn_timeout(game: &Game, player_state: &PlayerState) -> Result<bool> {
+    Ok(is_turn_timed_out_at(game, player_state, Clock::get()?.unix_timestamp))
+}
+
+/// Check if player's turn has timed out at a given time
+pub fn is_turn_timed_out_at(game: &Game, player_state: &PlayerState, now: i64) -> bool {
+    now - game.last_action_at >= game.turn_timeout + player_state.time_bank
 }
 
-/// Handle player timeout (auto-fold)
+/// Handle player timeout (auto check-fold)
+/// The player checks when nothing is owed, otherwise folds
 pub fn handle_player_timeout(
     game: &mut Game,
     player_state: &mut PlayerState,
 ) -> Result<()> {
+    validate_betting_stage(game)?;
     require!(
-        check_turn_timeout(game)?,
-        PokerError::InvalidAction
+        game.current_player_index == player_state.seat_index,
+        PokerError::NotPlayerTurn
     );
     
+    // Both the turn clock and the player's time bank must be used up
     require!(
-        game.current_player_index == player_state.seat_index,
-        PokerError::NotPlayerTurn
+        check_turn_timeout(game, player_state)?,
+        PokerError::InvalidAction
     );
+    player_state.time_bank = 0;
+    game.action_seq += 1;
+    
+    // Repeated timeouts sit the player out so they stop holding up the table
+    player_state.consecutive_timeouts += 1;
+    if player_state.consecutive_timeouts >= MAX_CONSECUTIVE_TIMEOUTS {
+        player_state.status = PlayerStatus::SittingOut;
+        msg!(
+            "[GAME FLOW] Player {} sat out after {} timeouts",
+            player_state.player,
+            player_state.consecutive_timeouts
+        );
+    }
+    
+    if game.current_bet == 0 || game.current_bet == player_state.current_bet {
+        msg!(
+            "[GAME FLOW] Player {} timed out and was auto-checked",
+            player_state.player
+        );
+        
+        return advance_to_next_player_or_stage(game);
+    }
     
     // Auto-fold the player
     player_state.fold();
@@ -192,6 +248,12 @@ pub fn handle_player_timeout(
         player_state.player
     );
     
+    if check_single_player_remaining(game) {
+        game.stage = GameStage::Finished;
+        msg!("[GAME FLOW] Only one player remaining, hand complete");
+        return Ok(());
+    }
+    
     // Advance to next player
     advance_to_next_active_player(game)?;
     
@@ -201,16 +263,16 @@ pub fn handle_player_timeout(
 /// Advance to next active player
 pub fn advance_to_next_active_player(game: &mut Game) -> Result<()> {
     let start_index = game.current_player_index;
-    let mut next_index = (start_index + 1) % game.player_count;
+    let mut next_index = (start_index + 1) % game.seat_count();
     
     // Find next active player who hasn't folded or gone all-in
     let mut found = false;
-    for _ in 0..game.player_count {
+    for _ in 0..game.seat_count() {
         if game.active_players[next_index as usize] {
             found = true;
             break;
         }
-        next_index = (next_index + 1) % game.player_count;
+        next_index = (next_index + 1) % game.seat_count();
     }
     
     require!(found, PokerError::InvalidGameStage);
@@ -223,7 +285,7 @@ pub fn advance_to_next_active_player(game: &mut Game) -> Result<()> {
 
 /// Check if only one player remains (all others folded)
 pub fn check_single_player_remaining(game: &Game) -> bool {
-    let active_count = game.active_players[..game.player_count as usize]
+    let active_count = game.active_players[..game.seat_count() as usize]
         .iter()
         .filter(|&&active| active)
         .count();
@@ -238,7 +300,7 @@ pub fn check_all_players_all_in(
 ) -> bool {
     let mut non_all_in_count = 0;
     
-    for i in 0..game.player_count as usize {
+    for i in 0..game.seat_count() as usize {
         if !game.active_players[i] {
             continue;
         }
@@ -254,26 +316,70 @@ pub fn check_all_players_all_in(
 }
 
 /// Start new hand (reset for next hand)
-pub fn start_new_hand(game: &mut Game) -> Result<()> {
+/// Player states are indexed by seat; busted and sitting-out players are skipped
+pub fn start_new_hand(game: &mut Game, player_states: &[PlayerState]) -> Result<()> {
+    // Players who still have chips and are not sitting out are dealt in
+    for (i, player_state) in player_states.iter().enumerate().take(game.seat_count() as usize) {
+        let seated = game.players[i] != Pubkey::default();
+        game.active_players[i] = seated && player_state.chip_stack > 0 && !player_state.is_sitting_out();
+        
+        if seated && !game.active_players[i] {
+            msg!("[GAME FLOW] Seat {} sits out this hand", i);
+        }
+    }
+    
+    require!(
+        !check_single_player_remaining(game),
+        PokerError::NotEnoughPlayers
+    );
+    
     // Rotate dealer button
     rotate_dealer_button(game)?;
     
+    // Players waiting for the big blind are only dealt in once it reaches them
+    loop {
+        let big_blind_seat = get_big_blind_position(game) as usize;
+        let still_waiting: Vec<usize> = (0..game.seat_count() as usize)
+            .filter(|&seat| {
+                game.active_players[seat]
+                    && player_states[seat].waiting_for_big_blind
+                    && seat != big_blind_seat
+            })
+            .collect();
+        
+        if still_waiting.is_empty() {
+            break;
+        }
+        
+        for seat in still_waiting {
+            game.active_players[seat] = false;
+            msg!("[GAME FLOW] Seat {} waits for the big blind", seat);
+        }
+    }
+    
+    require!(
+        !check_single_player_remaining(game),
+        PokerError::NotEnoughPlayers
+    );
+    
     // Reset game state
+    game.hand_number += 1;
     game.stage = GameStage::PreFlop;  // Start at PreFlop, not Waiting
     game.pot = 0;
+    game.dead_money = 0;
+    game.straddle_seat = None;
+    game.is_bomb_pot = false;
+    game.reset_run_it_twice();
     game.current_bet = 0;
     game.community_cards = [0; COMMUNITY_CARDS];
     game.community_cards_revealed = 0;
     game.deck_initialized = false;
     
-    // Reset active players (all players who haven't left)
-    for i in 0..game.player_count as usize {
-        if game.players[i] != Pubkey::default() {
-            game.active_players[i] = true;
-        }
-    }
-    
-    msg!("[GAME FLOW] New hand started. Dealer at seat {}", game.dealer_position);
+    msg!(
+        "[GAME FLOW] Hand {} started. Dealer at seat {}",
+        game.hand_number,
+        game.dealer_position
+    );
     
     Ok(())
 }
@@ -292,5 +398,77 @@ pub fn end_game(game: &mut Game) -> Result<()> {
     Ok(())
 }
 
-// Tests removed - would require implementing Default for Game
-// Integration tests should be used instead
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::seated_game;
+    
+    #[test]
+    fn test_start_new_hand_rotates_button() {
+        let (mut game, states) = seated_game(&[100, 100, 100]);
+        
+        start_new_hand(&mut game, &states).unwrap();
+        assert_eq!(game.hand_number, 1);
+        assert_eq!(game.stage, GameStage::PreFlop);
+        assert_eq!(game.dealer_position, 1);
+        assert_eq!((get_small_blind_position(&game), get_big_blind_position(&game)), (2, 0));
+        
+        start_new_hand(&mut game, &states).unwrap();
+        assert_eq!(game.hand_number, 2);
+        assert_eq!(game.dealer_position, 2);
+    }
+    
+    #[test]
+    fn test_start_new_hand_skips_busted_and_sitting_out() {
+        let (mut game, mut states) = seated_game(&[100, 0, 100, 100]);
+        states[2].status = PlayerStatus::SittingOut;
+        
+        // Seat 1 is busted and seat 2 sits out: the button skips both
+        start_new_hand(&mut game, &states).unwrap();
+        assert_eq!(&game.active_players[..4], &[true, false, false, true]);
+        assert_eq!(game.dealer_position, 3);
+        
+        // Heads-up the button posts the small blind
+        assert_eq!((get_small_blind_position(&game), get_big_blind_position(&game)), (3, 0));
+        
+        // Nobody left to play against
+        states[3].chip_stack = 0;
+        assert!(start_new_hand(&mut game, &states).is_err());
+    }
+    
+    #[test]
+    fn test_new_player_waits_for_big_blind() {
+        let (mut game, mut states) = seated_game(&[100, 100, 100, 100]);
+        
+        // Button moves to seat 1, so seat 3 is the big blind and is dealt in
+        states[3].waiting_for_big_blind = true;
+        start_new_hand(&mut game, &states).unwrap();
+        assert_eq!(get_big_blind_position(&game), 3);
+        assert!(game.active_players[3]);
+        
+        // Button moves to seat 2: seat 0 posts the big blind, seat 1 would be
+        // under the gun and sits this hand out
+        states[3].waiting_for_big_blind = false;
+        states[0].waiting_for_big_blind = true;
+        states[1].waiting_for_big_blind = true;
+        start_new_hand(&mut game, &states).unwrap();
+        assert_eq!(game.dealer_position, 2);
+        assert_eq!(get_big_blind_position(&game), 0);
+        assert_eq!(&game.active_players[..4], &[true, false, true, true]);
+    }
+    
+    #[test]
+    fn test_time_bank_extends_turn_timeout() {
+        let (mut game, mut states) = seated_game(&[100, 100]);
+        game.turn_timeout = 30;
+        game.last_action_at = 1_000;
+        states[0].time_bank = 20;
+        
+        assert!(!is_turn_timed_out_at(&game, &states[0], 1_049));
+        assert!(is_turn_timed_out_at(&game, &states[0], 1_050));
+        
+        // Without a bank only the turn clock applies
+        states[0].time_bank = 0;
+        assert!(is_turn_timed_out_at(&game, &states[0], 1_030));
+    }
+}
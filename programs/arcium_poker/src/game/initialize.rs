@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use super::state::Game;
+use super::state::{Game, GameConfig};
 use crate::shared::{constants::*, PokerError};
+use crate::token::get_conversion_rate;
 
 /// Initialize a new poker game
+/// Passing a token mint (with escrow accounts) makes it a token-denominated table
 pub fn handler(
     ctx: Context<crate::InitializeGame>,
     game_id: u64,
@@ -31,19 +33,39 @@ pub fn handler(
     require!(max_buy_in >= min_buy_in, PokerError::InvalidGameConfig);
     
     // Initialize game
-    let initialized_game = Game::new(
+    let initialized_game = Game::new(GameConfig {
         game_id,
-        ctx.accounts.authority.key(),
+        authority: ctx.accounts.authority.key(),
         small_blind,
         big_blind,
         min_buy_in,
         max_buy_in,
-        max_players_val,
-        ctx.bumps.game,
-    )?;
+        max_players: max_players_val,
+        bump: ctx.bumps.game,
+    })?;
     
     **game = initialized_game;
     
+    // Bind the table to an SPL token when a mint is provided
+    if let Some(token_mint) = &ctx.accounts.token_mint {
+        let (Some(escrow_token_account), Some(escrow_bump)) =
+            (&ctx.accounts.escrow_token_account, ctx.bumps.escrow_authority)
+        else {
+            return err!(PokerError::InvalidTokenAccounts);
+        };
+        
+        game.token_mint = token_mint.key();
+        game.token_escrow = escrow_token_account.key();
+        game.escrow_bump = escrow_bump;
+        game.conversion_rate = get_conversion_rate(token_mint.decimals);
+        
+        msg!(
+            "Token table: mint {}, {} tokens per chip",
+            game.token_mint,
+            game.conversion_rate.tokens_per_chip
+        );
+    }
+    
     msg!("Game {} initialized by {}", game_id, ctx.accounts.authority.key());
     msg!("Blinds: {}/{}, Buy-in: {}-{}", small_blind, big_blind, min_buy_in, max_buy_in);
     
@@ -0,0 +1,418 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use super::state::{Game, GameConfig};
+use crate::player::state::PlayerState;
+use crate::types::{GameStage, PlayerStatus};
+use crate::shared::PokerError;
+use crate::shared::constants::COMMUNITY_CARDS;
+
+/// Seats in the original Game layout
+const LEGACY_MAX_PLAYERS: usize = 6;
+
+/// Hole card slots in the original PlayerState layout
+const LEGACY_HOLE_CARDS: usize = 2;
+
+/// Game account as first deployed, before the layout was versioned
+/// Same discriminator as `Game`; told apart by its size
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyGame {
+    pub authority: Pubkey,
+    pub game_id: u64,
+    pub stage: GameStage,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub min_buy_in: u64,
+    pub max_buy_in: u64,
+    pub max_players: u8,
+    pub player_count: u8,
+    pub players: [Pubkey; LEGACY_MAX_PLAYERS],
+    pub active_players: [bool; LEGACY_MAX_PLAYERS],
+    pub dealer_position: u8,
+    pub current_player_index: u8,
+    pub pot: u64,
+    pub current_bet: u64,
+    pub players_acted: [bool; LEGACY_MAX_PLAYERS],
+    pub community_cards: [u8; COMMUNITY_CARDS],
+    pub community_cards_revealed: u8,
+    pub encrypted_deck: [u8; 32],
+    pub deck_initialized: bool,
+    pub started_at: i64,
+    pub last_action_at: i64,
+    pub shuffle_session_id: [u8; 32],
+    pub bump: u8,
+}
+
+impl LegacyGame {
+    /// Size of a legacy Game account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        8 + // game_id
+        1 + // stage
+        8 + // small_blind
+        8 + // big_blind
+        8 + // min_buy_in
+        8 + // max_buy_in
+        1 + // max_players
+        1 + // player_count
+        (32 * LEGACY_MAX_PLAYERS) + // players
+        LEGACY_MAX_PLAYERS + // active_players
+        1 + // dealer_position
+        1 + // current_player_index
+        8 + // pot
+        8 + // current_bet
+        LEGACY_MAX_PLAYERS + // players_acted
+        COMMUNITY_CARDS + // community_cards
+        1 + // community_cards_revealed
+        32 + // encrypted_deck
+        1 + // deck_initialized
+        8 + // started_at
+        8 + // last_action_at
+        32 + // shuffle_session_id
+        1; // bump
+    
+    /// The same table in the current layout
+    /// Stakes and limits carry over; everything else starts fresh
+    pub fn migrate(&self, now: i64) -> Game {
+        let mut game = Game::new_at(
+            GameConfig {
+                game_id: self.game_id,
+                authority: self.authority,
+                small_blind: self.small_blind,
+                big_blind: self.big_blind,
+                min_buy_in: self.min_buy_in,
+                max_buy_in: self.max_buy_in,
+                max_players: self.max_players,
+                bump: self.bump,
+            },
+            now,
+        );
+        game.started_at = self.started_at;
+        game
+    }
+    
+    /// The same table in the current layout, with its players kept in their seats
+    /// A hand left in progress is void: every bet goes back to the player's
+    /// stack. `legacy_players` must hold the state of every seated player.
+    pub fn migrate_with_players(
+        &self,
+        legacy_players: &[LegacyPlayerState],
+        now: i64,
+    ) -> Result<(Game, Vec<PlayerState>)> {
+        let mut game = self.migrate(now);
+        let mut player_states = Vec::with_capacity(legacy_players.len());
+        let mut refunded = 0u64;
+        
+        for legacy_player in legacy_players {
+            let seat = legacy_player.seat_index as usize;
+            require!(
+                seat < LEGACY_MAX_PLAYERS && self.players[seat] == legacy_player.player,
+                PokerError::PlayerNotInGame
+            );
+            
+            // Rejects a player passed twice
+            game.add_player_at_seat(legacy_player.player, legacy_player.seat_index)?;
+            
+            let chip_stack = legacy_player.chip_stack + legacy_player.total_bet_this_hand;
+            refunded += legacy_player.total_bet_this_hand;
+            game.escrowed_chips += chip_stack;
+            
+            player_states.push(PlayerState {
+                player: legacy_player.player,
+                game: legacy_player.game,
+                seat_index: legacy_player.seat_index,
+                status: PlayerStatus::Waiting,
+                chip_stack,
+                joined_at: legacy_player.joined_at,
+                last_action_at: now,
+                time_bank: game.time_bank_max,
+                bump: legacy_player.bump,
+                ..Default::default()
+            });
+        }
+        
+        require!(game.player_count == self.player_count, PokerError::PlayerNotInGame);
+        require!(refunded == self.pot, PokerError::EscrowImbalance);
+        
+        Ok((game, player_states))
+    }
+}
+
+/// PlayerState account as first deployed
+/// Same discriminator as `PlayerState`; told apart by its size
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyPlayerState {
+    pub player: Pubkey,
+    pub game: Pubkey,
+    pub seat_index: u8,
+    pub status: u8,
+    pub chip_stack: u64,
+    pub current_bet: u64,
+    pub total_bet_this_hand: u64,
+    pub encrypted_hole_cards: [u8; LEGACY_HOLE_CARDS],
+    pub has_cards: bool,
+    pub has_folded: bool,
+    pub is_all_in: bool,
+    pub joined_at: i64,
+    pub last_action_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyPlayerState {
+    /// Size of a legacy PlayerState account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // player
+        32 + // game
+        1 + // seat_index
+        1 + // status
+        8 + // chip_stack
+        8 + // current_bet
+        8 + // total_bet_this_hand
+        LEGACY_HOLE_CARDS + // encrypted_hole_cards
+        1 + // has_cards
+        1 + // has_folded
+        1 + // is_all_in
+        8 + // joined_at
+        8 + // last_action_at
+        1; // bump
+    
+    /// Load a legacy player state of `game` passed via remaining_accounts
+    fn load(account_info: &AccountInfo, game: &Pubkey) -> Result<Self> {
+        require!(account_info.owner == &crate::ID, PokerError::PlayerNotInGame);
+        require!(account_info.data_len() == Self::LEN, PokerError::PlayerNotInGame);
+        
+        let data = account_info.try_borrow_data()?;
+        require!(data[..8] == *PlayerState::DISCRIMINATOR, PokerError::PlayerNotInGame);
+        let legacy_player = Self::deserialize(&mut &data[8..])?;
+        
+        let expected = Pubkey::create_program_address(
+            &[
+                b"player",
+                game.as_ref(),
+                legacy_player.player.as_ref(),
+                &[legacy_player.bump],
+            ],
+            &crate::ID,
+        ).map_err(|_| PokerError::PlayerNotInGame)?;
+        require_keys_eq!(expected, account_info.key(), PokerError::PlayerNotInGame);
+        require_keys_eq!(legacy_player.game, *game, PokerError::PlayerNotInGame);
+        
+        Ok(legacy_player)
+    }
+}
+
+/// Upgrade a Game account from the original layout (authority only)
+///
+/// The account is reallocated to the current size and rewritten at
+/// GAME_ACCOUNT_VERSION. Seated players keep their seats and stacks: their
+/// PlayerState accounts, passed as remaining accounts, are upgraded too. A
+/// hand left in progress is void and its bets are returned. The authority
+/// pays the extra rent.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, crate::MigrateGame<'info>>) -> Result<()> {
+    let game_info = ctx.accounts.game.to_account_info();
+    require!(game_info.owner == &crate::ID, PokerError::InvalidGameConfig);
+    require!(game_info.data_len() == LegacyGame::LEN, PokerError::InvalidGameConfig);
+    
+    let legacy = {
+        let data = game_info.try_borrow_data()?;
+        require!(data[..8] == *Game::DISCRIMINATOR, PokerError::InvalidGameConfig);
+        LegacyGame::deserialize(&mut &data[8..])?
+    };
+    
+    // Only the table's own PDA, and only its authority
+    let game_key = Pubkey::create_program_address(
+        &[
+            b"game",
+            legacy.authority.as_ref(),
+            &legacy.game_id.to_le_bytes(),
+            &[legacy.bump],
+        ],
+        &crate::ID,
+    ).map_err(|_| PokerError::InvalidGameConfig)?;
+    require_keys_eq!(game_key, game_info.key(), PokerError::InvalidGameConfig);
+    require_keys_eq!(legacy.authority, ctx.accounts.authority.key(), PokerError::UnauthorizedSigner);
+    
+    let legacy_players = ctx.remaining_accounts
+        .iter()
+        .map(|account_info| LegacyPlayerState::load(account_info, &game_key))
+        .collect::<Result<Vec<_>>>()?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let (game, player_states) = legacy.migrate_with_players(&legacy_players, now)?;
+    
+    // The game PDA keeps holding every chip on top of rent
+    resize_account(
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &game_info,
+        Game::LEN,
+        game.escrowed_chips,
+    )?;
+    {
+        let mut data = game_info.try_borrow_mut_data()?;
+        let mut writer = &mut data[..];
+        game.try_serialize(&mut writer)?;
+    }
+    
+    for (account_info, player_state) in ctx.remaining_accounts.iter().zip(&player_states) {
+        resize_account(
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            account_info,
+            PlayerState::LEN,
+            0,
+        )?;
+        player_state.store(account_info)?;
+        
+        msg!(
+            "[MIGRATE] Seat {} kept by {} with {} chips",
+            player_state.seat_index,
+            player_state.player,
+            player_state.chip_stack
+        );
+    }
+    
+    msg!("[MIGRATE] Game {} upgraded to layout version {}", game.game_id, game.version);
+    
+    Ok(())
+}
+
+/// Grow an account to `len`, the authority topping up rent
+/// `held` is value the account holds beyond rent and must keep
+fn resize_account<'info>(
+    authority: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    account_info: &AccountInfo<'info>,
+    len: usize,
+    held: u64,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(len) + held;
+    let shortfall = required.saturating_sub(account_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: authority.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account_info.resize(len)?;
+    
+    Ok(())
+}
+
+// Uses the MigrateGame accounts struct in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::constants::GAME_ACCOUNT_VERSION;
+    
+    fn legacy_game() -> LegacyGame {
+        LegacyGame {
+            authority: Pubkey::new_unique(),
+            game_id: 7,
+            stage: GameStage::Finished,
+            small_blind: 50,
+            big_blind: 100,
+            min_buy_in: 2_000,
+            max_buy_in: 10_000,
+            max_players: 6,
+            player_count: 0,
+            players: [Pubkey::default(); LEGACY_MAX_PLAYERS],
+            active_players: [false; LEGACY_MAX_PLAYERS],
+            dealer_position: 3,
+            current_player_index: 0,
+            pot: 0,
+            current_bet: 0,
+            players_acted: [false; LEGACY_MAX_PLAYERS],
+            community_cards: [0; COMMUNITY_CARDS],
+            community_cards_revealed: 0,
+            encrypted_deck: [0; 32],
+            deck_initialized: false,
+            started_at: 1_000,
+            last_action_at: 1_500,
+            shuffle_session_id: [0; 32],
+            bump: 254,
+        }
+    }
+    
+    fn legacy_player(legacy: &mut LegacyGame, seat: u8, chip_stack: u64, bet: u64) -> LegacyPlayerState {
+        let player = Pubkey::new_unique();
+        legacy.players[seat as usize] = player;
+        legacy.player_count += 1;
+        legacy.pot += bet;
+        LegacyPlayerState {
+            player,
+            game: Pubkey::new_unique(),
+            seat_index: seat,
+            status: 1,
+            chip_stack,
+            current_bet: bet,
+            total_bet_this_hand: bet,
+            encrypted_hole_cards: [0; LEGACY_HOLE_CARDS],
+            has_cards: true,
+            has_folded: false,
+            is_all_in: false,
+            joined_at: 900,
+            last_action_at: 1_500,
+            bump: 253,
+        }
+    }
+    
+    #[test]
+    fn test_legacy_game_layout() {
+        let legacy = legacy_game();
+        
+        // The size used to detect legacy accounts matches the serialized layout
+        assert_eq!(legacy.try_to_vec().unwrap().len() + 8, LegacyGame::LEN);
+        assert_ne!(LegacyGame::LEN, Game::LEN);
+        
+        let game = legacy.migrate(2_000);
+        assert_eq!(game.version, GAME_ACCOUNT_VERSION);
+        assert_eq!(game.authority, legacy.authority);
+        assert_eq!((game.game_id, game.small_blind, game.big_blind), (7, 50, 100));
+        assert_eq!((game.min_buy_in, game.max_buy_in, game.max_players), (2_000, 10_000, 6));
+        assert_eq!(game.bump, 254);
+        assert_eq!(game.stage, GameStage::Waiting);
+        assert_eq!(game.last_action_at, 2_000);
+    }
+    
+    #[test]
+    fn test_seated_players_migrate_with_the_table() {
+        let mut legacy = legacy_game();
+        legacy.stage = GameStage::Flop;
+        let first = legacy_player(&mut legacy, 0, 1_900, 100);
+        let second = legacy_player(&mut legacy, 2, 4_800, 200);
+        assert_eq!(first.try_to_vec().unwrap().len() + 8, LegacyPlayerState::LEN);
+        
+        // The hand in progress is void and the bets go back to the stacks
+        let (game, player_states) = legacy
+            .migrate_with_players(&[second.clone(), first.clone()], 2_000)
+            .unwrap();
+        assert_eq!(game.player_count, 2);
+        assert_eq!((game.players[0], game.players[2]), (first.player, second.player));
+        assert_eq!(game.escrowed_chips, 7_000);
+        assert_eq!(game.pot, 0);
+        
+        assert_eq!(player_states[0].seat_index, 2);
+        assert_eq!(player_states[0].chip_stack, 5_000);
+        assert_eq!(player_states[1].chip_stack, 2_000);
+        assert_eq!(player_states[1].status, PlayerStatus::Waiting);
+        assert_eq!(player_states[1].bump, 253);
+        assert_eq!(player_states[1].time_bank, game.time_bank_max);
+        
+        // Every seated player must be passed, each once
+        assert!(legacy.migrate_with_players(std::slice::from_ref(&first), 2_000).is_err());
+        assert!(legacy.migrate_with_players(&[first.clone(), first.clone()], 2_000).is_err());
+        
+        // Only players of this table
+        let mut stranger = second.clone();
+        stranger.player = Pubkey::new_unique();
+        assert!(legacy.migrate_with_players(&[first, stranger], 2_000).is_err());
+    }
+}
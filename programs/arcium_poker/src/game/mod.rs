@@ -1,14 +1,31 @@
 pub mod state;
 pub mod initialize;
+pub mod configure;
 pub mod start;
+pub mod new_hand;
+pub mod crank;
+pub mod rake;
+pub mod surplus;
+pub mod close;
+pub mod migrate;
 pub mod logic;
 pub mod flow;
 
+#[cfg(test)]
+pub mod test_utils;
+
 pub use state::*;
 
 // Export the handler functions
 pub use initialize::handler as initialize_handler;
+pub use configure::{handler as configure_handler, schedule_bomb_pot, TableConfigParams};
 pub use start::handler as start_handler;
+pub use new_hand::handler as new_hand_handler;
+pub use crank::handler as crank_timeout_handler;
+pub use rake::handler as sweep_rake_handler;
+pub use surplus::handler as sweep_surplus_handler;
+pub use close::handler as close_table_handler;
+pub use migrate::handler as migrate_game_handler;
 
 // Export flow control functions
 pub use flow::{
@@ -17,6 +34,7 @@ pub use flow::{
     rotate_dealer_button,
     get_small_blind_position,
     get_big_blind_position,
+    get_straddle_position,
     check_turn_timeout,
     handle_player_timeout,
     advance_to_next_active_player,
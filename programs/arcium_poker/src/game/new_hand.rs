@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use super::flow::start_new_hand;
+use super::start::begin_hand;
+use super::close::close_player_state;
+use super::state::Game;
+use crate::player::state::{PlayerState, SeatedPlayers};
+use crate::advanced::{Jackpot, rake_pot, attribute_rake, feed_jackpot};
+use crate::security::validate_chip_conservation;
+use crate::types::{GameStage, PlayerStatus};
+use crate::shared::PokerError;
+
+/// Start the next hand at a cash table
+/// 
+/// Awards an uncontested pot left by folds (after rake), applies auto-rebuys, rotates the button, sits out
+/// busted players, frees the seats of players who left mid-hand and
+/// reshuffles before dealing and posting blinds.
+/// Remaining accounts: PlayerState accounts for every occupied seat, in any
+/// order, followed by the wallet of each player who left mid-hand, in seat
+/// order. Their PlayerState is closed and its rent returned so they can rejoin.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::StartGame<'info>>,
+    player_entropy: Vec<[u8; 32]>, // Each player provides fresh randomness
+) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let game = &mut ctx.accounts.game;
+    let seat_count = game.seat_count() as usize;
+    
+    // Previous hand must be complete
+    require!(
+        game.stage == GameStage::Finished,
+        PokerError::GameNotFinished
+    );
+    
+    let (state_accounts, wallet_accounts) = ctx.remaining_accounts.split_at(
+        (game.player_count as usize).min(ctx.remaining_accounts.len()),
+    );
+    let mut seated = SeatedPlayers::load(state_accounts, game, &game_key)?;
+    
+    require!(
+        ctx.accounts.jackpot.is_some() == game.has_jackpot,
+        PokerError::InvalidJackpot
+    );
+    
+    award_uncontested_pot(game, &mut seated.states, ctx.accounts.jackpot.as_deref_mut())?;
+    
+    // Busted players with auto-rebuy reload from their reserve
+    for (seat, player_state) in seated.states.iter_mut().enumerate() {
+        if !game.is_seat_occupied(seat as u8) || player_state.status == PlayerStatus::Left {
+            continue;
+        }
+        
+        let rebuy = player_state.try_auto_rebuy(game.max_buy_in);
+        if rebuy > 0 {
+            msg!("[NEW HAND] Seat {} auto-rebuys for {}", seat, rebuy);
+        }
+    }
+    
+    validate_chip_conservation(
+        game,
+        &seated.states,
+        ctx.accounts.jackpot.as_ref().map_or(0, |jackpot| jackpot.balance),
+    )?;
+    
+    // A player who left while all-in and then won keeps the seat, sitting
+    // out, until they cash out with leave_game
+    for player_state in seated.states.iter_mut() {
+        if player_state.status == PlayerStatus::Left && player_state.chip_stack > 0 {
+            player_state.status = PlayerStatus::SittingOut;
+            msg!(
+                "[NEW HAND] Seat {} left and won {}, sitting out until cashed out",
+                player_state.seat_index,
+                player_state.chip_stack
+            );
+        }
+    }
+    
+    seated.store_all()?;
+    
+    // Seats of players who left during the last hand are freed now
+    let mut wallets = wallet_accounts.iter();
+    for seat in 0..seat_count {
+        if game.is_seat_occupied(seat as u8) && seated.states[seat].status == PlayerStatus::Left {
+            let player = game.players[seat];
+            let wallet = wallets.next().ok_or(PokerError::PlayerNotInGame)?;
+            require_keys_eq!(wallet.key(), player, PokerError::PlayerNotInGame);
+            
+            game.remove_player(&player)?;
+            if let Some(state_info) = seated.accounts[seat].take() {
+                close_player_state(state_info, wallet)?;
+            }
+            
+            msg!("[NEW HAND] Seat {} freed after player left", seat);
+        }
+    }
+    
+    start_new_hand(game, &seated.states)?;
+    
+    let player_accounts: Vec<AccountInfo<'info>> = seated.accounts
+        .iter()
+        .flatten()
+        .map(|&account_info| account_info.clone())
+        .collect();
+    
+    begin_hand(ctx.accounts, &player_accounts, player_entropy)
+}
+
+/// Everyone else folded: the last player in the hand takes the pot
+/// House rake (and the jackpot drop) comes off the pot before it is awarded.
+/// The winner cannot leave until this runs (see `player::leave`).
+pub(crate) fn award_uncontested_pot(
+    game: &mut Game,
+    player_states: &mut [PlayerState],
+    jackpot: Option<&mut Jackpot>,
+) -> Result<()> {
+    if game.pot == 0 {
+        return Ok(());
+    }
+    
+    let contenders: Vec<usize> = (0..game.seat_count() as usize)
+        .filter(|&seat| game.active_players[seat] && !player_states[seat].has_folded)
+        .collect();
+    require!(contenders.len() == 1, PokerError::GameNotFinished);
+    
+    // Only the rake that reaches the treasury earns rakeback, not the jackpot drop
+    let rake = rake_pot(game)?;
+    let drop = jackpot.map_or(0, |jackpot| feed_jackpot(game, jackpot));
+    attribute_rake(rake - drop, player_states);
+    
+    let winner = contenders[0];
+    player_states[winner].add_winnings(game.pot);
+    
+    msg!("[NEW HAND] Seat {} wins uncontested pot of {}", winner, game.pot);
+    game.pot = 0;
+    
+    Ok(())
+}
+
+// Uses the StartGame accounts struct in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::seated_game;
+    use crate::player::leave::validate_can_leave;
+    
+    #[test]
+    fn test_fold_to_one_then_leave_then_new_hand() {
+        let (mut game, mut states) = seated_game(&[100, 100]);
+        
+        // Seat 0 folds to seat 1's raise; the hand ends with the pot unpaid
+        game.stage = GameStage::Finished;
+        game.pot = 30;
+        states[0].total_bet_this_hand = 10;
+        states[0].has_folded = true;
+        game.active_players[0] = false;
+        states[1].total_bet_this_hand = 20;
+        
+        // The winner must wait for the pot; the folded player may go
+        assert!(validate_can_leave(&game, &states[1]).is_err());
+        assert!(validate_can_leave(&game, &states[0]).is_ok());
+        
+        // new_hand pays the last player in the hand
+        award_uncontested_pot(&mut game, &mut states, None).unwrap();
+        assert_eq!(states[1].chip_stack, 130);
+        assert_eq!(game.pot, 0);
+        assert!(validate_can_leave(&game, &states[1]).is_ok());
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::shared::PokerError;
+use crate::token::{TokenRoute, payout_chips, current_balance};
+use crate::security::validate_escrow_invariants;
+
+/// Sweep rake collected in the game escrow to the house treasury
+/// 
+/// SOL rake moves to the Treasury PDA, token rake to the treasury vault for
+/// the table's mint. The treasury ledger records the amount received.
+pub fn handler(ctx: Context<crate::SweepRake>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let amount = game.collected_rake;
+    
+    require!(amount > 0, PokerError::InvalidBetAmount);
+    
+    let ledger = &mut ctx.accounts.treasury_ledger;
+    let vault_balance_before = match &ctx.accounts.treasury_vault {
+        Some(vault) => {
+            require_keys_eq!(vault.key(), ledger.vault, PokerError::InvalidTokenAccounts);
+            vault.amount
+        }
+        None => 0,
+    };
+    
+    game.collected_rake = 0;
+    
+    // Pay from game escrow (lamports, or tokens on a token table)
+    let route = TokenRoute::for_game(
+        game,
+        ctx.accounts.treasury_vault.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    let is_token_route = route.is_some();
+    payout_chips(
+        game,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.escrow_authority.as_ref().map(|authority| authority.as_ref()),
+        route,
+        amount,
+    )?;
+    
+    // Token ledgers record what reached the vault, net of any transfer fee
+    let received = match &ctx.accounts.treasury_vault {
+        Some(vault) if is_token_route => current_balance(vault)?.saturating_sub(vault_balance_before),
+        _ => amount,
+    };
+    ledger.record_deposit(received);
+    
+    validate_escrow_invariants(
+        game,
+        ctx.remaining_accounts,
+        None,
+        ctx.accounts.jackpot.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+    )?;
+    
+    msg!(
+        "[RAKE] Swept {} to treasury (ledger total: {})",
+        amount,
+        ledger.total_collected
+    );
+    
+    Ok(())
+}
+
+// Uses the SweepRake accounts struct in lib.rs
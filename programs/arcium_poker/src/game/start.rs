@@ -1,14 +1,22 @@
 use anchor_lang::prelude::*;
 use super::state::Game;
-use crate::player::state::PlayerState;
-use crate::arcium::mpc_shuffle::{mpc_shuffle_deck, ShuffleParams};
+use super::flow::{
+    advance_game_stage,
+    get_big_blind_position,
+    get_first_player_for_round,
+    get_small_blind_position,
+    get_straddle_position,
+};
+use crate::player::state::{PlayerState, SeatedPlayers};
+use crate::arcium::mpc_shuffle::MxeShuffleParams;
 use crate::arcium::mpc_deal::{mpc_deal_card, DealParams};
-use crate::types::GameStage;
+use crate::arcium::integration::shuffle_computation_offset;
+use crate::types::{AnteType, GameStage};
 use crate::shared::{constants::*, PokerError};
 
 /// Start the poker game - triggers MPC shuffle and deals hole cards
-pub fn handler(
-    ctx: Context<crate::StartGame>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::StartGame<'info>>,
     player_entropy: Vec<[u8; 32]>, // Each player provides randomness
 ) -> Result<()> {
     let game = &mut ctx.accounts.game;
@@ -22,42 +30,106 @@ pub fn handler(
         game.player_count >= MIN_PLAYERS as u8,
         PokerError::NotEnoughPlayers
     );
+    
+    msg!("[GAME START] Starting game {} with {} players", game.game_id, game.player_count);
+    
+    // First hand: dealer button starts at the lowest occupied seat
+    game.hand_number = 1;
+    game.dealer_position = (0..game.seat_count())
+        .find(|&seat| game.is_seat_occupied(seat))
+        .unwrap_or(0);
+    
+    begin_hand(ctx.accounts, ctx.remaining_accounts, player_entropy)
+}
+
+/// Shuffle, deal and post forced bets for the hand in `game.hand_number`
+/// 
+/// Shared by the first hand and every following hand. When PlayerState
+/// accounts for the seated players are provided, every seated PlayerState is
+/// reset, busted players are sat out and hole cards are stored on each account.
+pub(crate) fn begin_hand<'info>(
+    accounts: &mut crate::StartGame<'info>,
+    player_accounts: &[AccountInfo<'info>],
+    player_entropy: Vec<[u8; 32]>,
+) -> Result<()> {
+    let game_key = accounts.game.key();
+    let seat_count = accounts.game.seat_count() as usize;
+    
     require!(
-        player_entropy.len() == game.player_count as usize,
+        player_entropy.len() == accounts.game.player_count as usize,
         PokerError::InvalidGameConfig
     );
     
-    msg!("[GAME START] Starting game {} with {} players", game.game_id, game.player_count);
+    // ========================================================================
+    // STEP 1: RESET SEATED PLAYERS
+    // ========================================================================
+    let mut seated = if player_accounts.is_empty() {
+        None
+    } else {
+        Some(SeatedPlayers::load(player_accounts, &accounts.game, &game_key)?)
+    };
+    
+    if let Some(seated) = seated.as_mut() {
+        let game = &mut accounts.game;
+        for seat in 0..seat_count {
+            if !game.is_seat_occupied(seat as u8) {
+                continue;
+            }
+            
+            let player_state = &mut seated.states[seat];
+            player_state.reset_for_new_hand();
+            player_state.refill_time_bank(game);
+            
+            // Busted players sit out until they add chips
+            if player_state.chip_stack == 0 {
+                game.active_players[seat] = false;
+                msg!("[GAME START] Seat {} is busted and sits out", seat);
+            } else if player_state.is_sitting_out() {
+                game.active_players[seat] = false;
+                msg!("[GAME START] Seat {} is sitting out", seat);
+            }
+        }
+    }
+    
+    let active_count = accounts.game.active_players[..seat_count]
+        .iter()
+        .filter(|&&active| active)
+        .count();
+    require!(active_count >= MIN_PLAYERS, PokerError::NotEnoughPlayers);
+    
+    // Rake caps depend on how many players are dealt in
+    accounts.game.players_dealt = active_count as u8;
+    accounts.game.hand_rake = 0;
+    
+    msg!("[GAME START] Hand {} with {} players", accounts.game.hand_number, active_count);
     
     // ========================================================================
-    // STEP 1: ARCIUM MPC SHUFFLE 🔐
+    // STEP 2: ARCIUM MPC SHUFFLE 🔐
     // ========================================================================
     msg!("[ARCIUM MPC] Initiating secure shuffle...");
     
-    // Collect all player pubkeys
-    let players: Vec<Pubkey> = game.players[..game.player_count as usize]
+    // Collect all seated player pubkeys
+    let players: Vec<Pubkey> = accounts.game.players[..seat_count]
         .iter()
         .copied()
+        .filter(|player| *player != Pubkey::default())
         .collect();
     
     // Perform MPC shuffle with all players contributing entropy
     // Use REAL Arcium MPC with MXE accounts
-    use crate::arcium::mpc_shuffle::MxeShuffleParams;
-    
-    // Generate computation offset (unique ID for this computation)
-    let computation_offset = game.game_id.to_le_bytes();
-    
+    // Computation offset is unique per game and hand so every hand gets a fresh shuffle
     let mxe_shuffle_params = MxeShuffleParams {
-        mxe_program: Some(ctx.accounts.mxe_program.clone()),
-        comp_def: Some(ctx.accounts.comp_def_account.clone()),
-        mempool: Some(ctx.accounts.mempool_account.clone()),
-        cluster: Some(ctx.accounts.cluster_account.clone()),
-        computation_account: Some(ctx.accounts.computation_account.clone()),
-        authority: Some(ctx.accounts.authority.to_account_info()),
-        encrypted_entropy: player_entropy.clone(),
-        computation_offset,
-        player_pubkeys: players.clone(),
-        game_id: game.game_id,
+        mxe_program: Some(accounts.mxe_program.clone()),
+        comp_def: Some(accounts.comp_def_account.clone()),
+        mempool: Some(accounts.mempool_account.clone()),
+        cluster: Some(accounts.cluster_account.clone()),
+        computation_account: Some(accounts.computation_account.clone()),
+        authority: Some(accounts.authority.to_account_info()),
+        encrypted_entropy: player_entropy,
+        computation_offset: shuffle_computation_offset(accounts.game.game_id, accounts.game.hand_number),
+        player_pubkeys: players,
+        game_id: accounts.game.game_id,
+        deck_size: accounts.game.variant.deck_size(),
     };
     
     let shuffle_result = crate::arcium::mpc_shuffle::mpc_shuffle_deck_with_mxe(mxe_shuffle_params)?;
@@ -71,28 +143,34 @@ pub fn handler(
         &shuffle_result.commitment[..8]
     );
     
+    let game = &mut accounts.game;
+    
     // Store shuffle result in game state
     game.encrypted_deck = shuffle_result.session_id;
+    game.shuffle_session_id = shuffle_result.session_id;
     game.deck_initialized = true;
     
     // ========================================================================
-    // STEP 2: DEAL ENCRYPTED HOLE CARDS 🎴
+    // STEP 3: DEAL ENCRYPTED HOLE CARDS 🎴
     // ========================================================================
     msg!("[DEALING] Dealing encrypted hole cards to all players...");
     
-    // Deal 2 hole cards to each player (encrypted via Arcium MPC)
+    // Deal hole cards to each player in the hand (encrypted via Arcium MPC)
+    let hole_cards = game.variant.hole_cards();
     let mut card_index = 0u8;
     
-    for (i, player_account) in ctx.remaining_accounts.iter().enumerate() {
-        if i >= game.player_count as usize {
-            break;
+    for seat in 0..seat_count {
+        if !game.active_players[seat] {
+            continue;
         }
         
-        let player_pubkey = game.players[i];
-        msg!("[DEALING] Dealing to player {} at seat {}", player_pubkey, i);
+        let player_pubkey = game.players[seat];
+        msg!("[DEALING] Dealing to player {} at seat {}", player_pubkey, seat);
+        
+        let mut dealt = [0u8; MAX_HOLE_CARDS];
         
         // Deal hole cards using Arcium MPC
-        for hole_card_num in 0..HOLE_CARDS {
+        for (hole_card_num, dealt_card) in dealt.iter_mut().enumerate().take(hole_cards) {
             let deal_params = DealParams {
                 card_index: shuffle_result.shuffled_indices[card_index as usize],
                 player: player_pubkey,
@@ -101,35 +179,36 @@ pub fn handler(
             };
             
             let encrypted_card = mpc_deal_card(deal_params)?;
+            *dealt_card = encrypted_card.encrypted_index;
             
             msg!(
                 "[DEALING] Card {}/{} dealt to seat {} (encrypted index: {})",
                 hole_card_num + 1,
-                HOLE_CARDS,
-                i,
+                hole_cards,
+                seat,
                 encrypted_card.encrypted_index
             );
             
             card_index += 1;
         }
+        
+        // Store encrypted hole cards on the player's state
+        if let Some(seated) = seated.as_mut() {
+            seated.states[seat].encrypted_hole_cards = dealt;
+            seated.states[seat].has_cards = true;
+        }
     }
     
     // ========================================================================
-    // STEP 3: INITIALIZE GAME STATE & POST BLINDS
+    // STEP 4: INITIALIZE HAND STATE
     // ========================================================================
     
     // Set game stage to PreFlop
     game.stage = GameStage::PreFlop;
     
-    // Set dealer button (starts at position 0)
-    game.dealer_position = 0;
-    
-    // Calculate blind positions
-    let small_blind_seat = (game.dealer_position + 1) % game.player_count;
-    let big_blind_seat = (game.dealer_position + 2) % game.player_count;
-    
-    // First player after big blind acts first
-    game.current_player_index = (game.dealer_position + 3) % game.player_count;
+    // Calculate blind positions from the button, skipping players sitting out
+    let small_blind_seat = get_small_blind_position(game);
+    let big_blind_seat = get_big_blind_position(game);
     
     // Set timestamp
     game.started_at = Clock::get()?.unix_timestamp;
@@ -137,36 +216,59 @@ pub fn handler(
     
     // Reset pot and bets
     game.pot = 0;
+    game.dead_money = 0;
     game.current_bet = game.big_blind;
+    game.players_acted = [false; MAX_PLAYERS];
+    game.community_cards = [0; COMMUNITY_CARDS];
+    game.community_cards_revealed = 0;
+    game.straddle_seat = None;
+    game.action_seq = 0;
+    game.reset_run_it_twice();
+    
+    // First player after big blind acts first
+    game.current_player_index = get_first_player_for_round(game);
+    
+    // A scheduled bomb pot replaces blinds with a table-wide ante
+    game.is_bomb_pot = game.bomb_pot_scheduled;
+    game.bomb_pot_scheduled = false;
+    if game.is_bomb_pot {
+        require!(seated.is_some(), PokerError::InvalidGameConfig);
+    }
     
-    msg!("[GAME START] Game initialized!");
+    msg!("[GAME START] Hand initialized!");
     msg!("[GAME START] Dealer button at seat {}", game.dealer_position);
     msg!("[GAME START] Small blind seat: {}, Big blind seat: {}", small_blind_seat, big_blind_seat);
     msg!("[GAME START] Current player: seat {}", game.current_player_index);
     msg!("[GAME START] Stage: {:?}", game.stage);
     
     // ========================================================================
-    // STEP 4: POST BLINDS AUTOMATICALLY
+    // STEP 5: POST ANTES AND BLINDS AUTOMATICALLY
     // ========================================================================
     msg!("[BLINDS] Posting blinds automatically...");
     
-    // Post blinds if player accounts are provided in remaining_accounts
-    if ctx.remaining_accounts.len() >= game.player_count as usize {
-        post_blind(
-            &ctx.remaining_accounts[small_blind_seat as usize],
-            game.small_blind,
-            small_blind_seat,
-            &mut game.pot,
-        )?;
+    // Post forced bets if player accounts are provided in remaining_accounts
+    if let Some(seated) = seated.as_mut() {
+        track_missed_blinds(game, &mut seated.states, small_blind_seat, big_blind_seat);
         
-        post_blind(
-            &ctx.remaining_accounts[big_blind_seat as usize],
-            game.big_blind,
+        post_forced_bets(
+            game,
+            &mut seated.states,
+            small_blind_seat,
             big_blind_seat,
-            &mut game.pot,
         )?;
+        seated.store_all()?;
         
         msg!("[BLINDS] Blinds posted successfully. Pot: {}", game.pot);
+        
+        if game.is_bomb_pot {
+            // Bomb pots skip preflop betting and start on the flop
+            msg!("[BOMB POT] Dealing straight to the flop");
+            advance_game_stage(game)?;
+        } else if game.straddle_seat.is_some() {
+            // Action starts after the straddler, who acts last preflop
+            game.current_player_index = get_first_player_for_round(game);
+            msg!("[STRADDLE] First to act: seat {}", game.current_player_index);
+        }
     } else {
         msg!("[BLINDS] No player accounts - blinds enforced via current_bet");
         msg!("[BLINDS] Players must call {} to match big blind", game.current_bet);
@@ -175,37 +277,330 @@ pub fn handler(
     Ok(())
 }
 
+/// Post antes and blinds for a new hand
+/// Player states are indexed by seat
+pub(crate) fn post_forced_bets(
+    game: &mut Game,
+    player_states: &mut [PlayerState],
+    small_blind_seat: u8,
+    big_blind_seat: u8,
+) -> Result<()> {
+    // Bomb pot: every player dealt in antes the same amount, no blinds
+    if game.is_bomb_pot {
+        let bomb_pot_ante = game.bomb_pot_ante;
+        for (seat, player_state) in player_states.iter_mut().enumerate() {
+            if game.active_players[seat] {
+                post_ante(player_state, bomb_pot_ante, game);
+            }
+        }
+        return Ok(());
+    }
+    
+    // Standard antes come first, from every player dealt in
+    if game.ante_type == AnteType::Standard {
+        let ante = game.ante;
+        for (seat, player_state) in player_states.iter_mut().enumerate() {
+            if game.active_players[seat] {
+                post_ante(player_state, ante, game);
+            }
+        }
+    }
+    
+    post_blind(
+        &mut player_states[small_blind_seat as usize],
+        game.small_blind,
+        &mut game.pot,
+    )?;
+    
+    post_blind(
+        &mut player_states[big_blind_seat as usize],
+        game.big_blind,
+        &mut game.pot,
+    )?;
+    
+    // Voluntary straddle, if the player in the straddle position opted in
+    if let Some(straddle_seat) = get_straddle_position(game) {
+        post_straddle(&mut player_states[straddle_seat as usize], game)?;
+    }
+    
+    // Returning players settle blinds they missed while sitting out
+    for (seat, player_state) in player_states.iter_mut().enumerate() {
+        if game.active_players[seat] && player_state.has_missed_blinds() {
+            post_missed_blinds(player_state, game, small_blind_seat, big_blind_seat)?;
+        }
+    }
+    
+    // Big blind ante: the blind takes priority, the ante comes out of what is left
+    if game.ante_type == AnteType::BigBlind {
+        let ante = game.ante;
+        post_ante(&mut player_states[big_blind_seat as usize], ante, game);
+    }
+    
+    if game.dead_money > 0 {
+        msg!("[ANTES] Dead money in pot: {}", game.dead_money);
+    }
+    
+    Ok(())
+}
+
+/// Mark blinds passing over seated players who are not dealt in
+/// Seats between the button and the small blind miss the small blind, seats
+/// between the small and big blind miss the big blind
+fn track_missed_blinds(
+    game: &Game,
+    player_states: &mut [PlayerState],
+    small_blind_seat: u8,
+    big_blind_seat: u8,
+) {
+    let seat_count = game.seat_count();
+    // Seats strictly between `from` and `to`, going round the table
+    let offset = |seat: u8, from: u8| (seat + seat_count - from) % seat_count;
+    let missed = |seat: u8, from: u8, to: u8| {
+        offset(seat, from) > 0 && offset(seat, from) < offset(to, from)
+    };
+    
+    for (seat, player_state) in player_states.iter_mut().enumerate() {
+        let seat = seat as u8;
+        if !game.is_seat_occupied(seat) || game.active_players[seat as usize] || player_state.chip_stack == 0 {
+            continue;
+        }
+        
+        if missed(seat, game.dealer_position, small_blind_seat) {
+            player_state.missed_small_blind = true;
+            msg!("[BLINDS] Seat {} missed the small blind", seat);
+        }
+        if missed(seat, small_blind_seat, big_blind_seat) {
+            player_state.missed_big_blind = true;
+            msg!("[BLINDS] Seat {} missed the big blind", seat);
+        }
+    }
+}
+
+/// Post blinds a returning player missed while sitting out
+/// The missed big blind is live, the missed small blind is dead money.
+/// A player in the big blind is covered by their blind.
+fn post_missed_blinds(
+    player_state: &mut PlayerState,
+    game: &mut Game,
+    small_blind_seat: u8,
+    big_blind_seat: u8,
+) -> Result<()> {
+    let seat = player_state.seat_index;
+    
+    if seat != big_blind_seat {
+        if player_state.missed_big_blind {
+            let big_blind = game.big_blind;
+            if seat == small_blind_seat {
+                // Already posting a blind, so the missed big blind is dead
+                post_ante(player_state, big_blind, game);
+            } else {
+                post_blind(player_state, big_blind, &mut game.pot)?;
+            }
+        }
+        
+        if player_state.missed_small_blind {
+            let small_blind = game.small_blind;
+            post_ante(player_state, small_blind, game);
+        }
+    }
+    
+    player_state.clear_missed_blinds();
+    
+    Ok(())
+}
+
 /// Helper function to post a blind
-fn post_blind<'info>(
-    player_account_info: &AccountInfo<'info>,
+fn post_blind(
+    player_state: &mut PlayerState,
     blind_amount: u64,
-    seat_index: u8,
     pot: &mut u64,
 ) -> Result<()> {
-    // Borrow and deserialize player state
-    let mut data = player_account_info.try_borrow_mut_data()?;
-    
-    // Deserialize (try_deserialize handles discriminator automatically)
-    let mut player_data = &data[..];
-    let mut player_state = crate::player::state::PlayerState::try_deserialize(&mut player_data)?;
+    // A short stack posts what it has and is all-in
+    let posted = player_state.post_blind(blind_amount);
+    *pot += posted;
     
-    // Verify seat
-    require!(
-        player_state.seat_index == seat_index,
-        PokerError::InvalidAction
-    );
+    msg!("[BLINDS] Posted {} chips from seat {}", posted, player_state.seat_index);
     
-    // Post blind
-    player_state.place_bet(blind_amount)?;
-    *pot += blind_amount;
+    Ok(())
+}
+
+/// Helper function to post a straddle (a live blind that raises the preflop bet)
+fn post_straddle(
+    player_state: &mut PlayerState,
+    game: &mut Game,
+) -> Result<()> {
+    let straddle_amount = game.big_blind * STRADDLE_MULTIPLIER;
+    if !player_state.straddle_opt_in || player_state.chip_stack < straddle_amount {
+        return Ok(());
+    }
     
-    // Serialize back (includes discriminator)
-    let mut writer = &mut data[..];
-    player_state.try_serialize(&mut writer)?;
+    // The straddle becomes the bet to call, so the minimum raise scales with it
+    player_state.post_blind(straddle_amount);
+    game.pot += straddle_amount;
+    game.current_bet = straddle_amount;
+    game.straddle_seat = Some(player_state.seat_index);
     
-    msg!("[BLINDS] Posted {} chips from seat {}", blind_amount, seat_index);
+    msg!("[STRADDLE] Posted {} chips from seat {}", straddle_amount, player_state.seat_index);
     
     Ok(())
 }
 
-// StartGame struct moved to lib.rs at crate root (required by Anchor)
\ No newline at end of file
+/// Helper function to post an ante as dead money
+fn post_ante(
+    player_state: &mut PlayerState,
+    ante_amount: u64,
+    game: &mut Game,
+) {
+    // A short stack posts what it has and is all-in
+    let posted = player_state.post_ante(ante_amount);
+    game.pot += posted;
+    game.dead_money += posted;
+    
+    msg!("[ANTES] Posted {} chips from seat {}", posted, player_state.seat_index);
+}
+
+// StartGame struct moved to lib.rs at crate root (required by Anchor)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::seated_game;
+    use crate::betting::validator::validate_raise;
+    use crate::types::StraddleType;
+    
+    /// Seated players holding `stacks`, all dealt into the next hand
+    fn dealt_in_game(stacks: &[u64]) -> (Game, Vec<PlayerState>) {
+        let (game, mut states) = seated_game(stacks);
+        for player_state in states.iter_mut().take(stacks.len()) {
+            player_state.has_cards = true;
+        }
+        (game, states)
+    }
+    
+    #[test]
+    fn test_short_stack_posts_blind_all_in_after_ante() {
+        let (mut game, mut states) = dealt_in_game(&[100, 6, 12]);
+        game.ante_type = AnteType::Standard;
+        game.ante = 3;
+        
+        post_forced_bets(&mut game, &mut states, 1, 2).unwrap();
+        
+        // Small blind: 3 ante leaves 3 of a 5 blind
+        assert_eq!(states[1].chip_stack, 0);
+        assert_eq!(states[1].current_bet, 3);
+        assert!(states[1].is_all_in);
+        
+        // Big blind: 3 ante leaves 9 of a 10 blind
+        assert_eq!(states[2].chip_stack, 0);
+        assert_eq!(states[2].current_bet, 9);
+        assert!(states[2].is_all_in);
+        
+        assert_eq!(game.pot, 9 + 3 + 9);
+        assert_eq!(game.dead_money, 9);
+    }
+    
+    #[test]
+    fn test_blind_after_ante_takes_whole_stack() {
+        // The ante exactly consumes the stack, nothing is left for the blind
+        let (mut game, mut states) = dealt_in_game(&[100, 100, 3]);
+        game.ante_type = AnteType::Standard;
+        game.ante = 3;
+        
+        post_forced_bets(&mut game, &mut states, 1, 2).unwrap();
+        
+        assert_eq!(states[2].current_bet, 0);
+        assert_eq!(states[2].total_bet_this_hand, 3);
+        assert!(states[2].is_all_in);
+        assert_eq!(game.pot, 9 + 5);
+    }
+    
+    #[test]
+    fn test_straddle_acts_last_and_sets_min_raise() {
+        let (mut game, mut states) = dealt_in_game(&[500, 500, 500, 500]);
+        game.stage = GameStage::PreFlop;
+        game.straddle_type = StraddleType::UnderTheGun;
+        states[3].straddle_opt_in = true;
+        
+        // Dealer 0, blinds 1 and 2, straddle under the gun in seat 3
+        post_forced_bets(&mut game, &mut states, 1, 2).unwrap();
+        assert_eq!(game.straddle_seat, Some(3));
+        assert_eq!(states[3].current_bet, 20);
+        assert_eq!(game.current_bet, 20);
+        assert_eq!(game.pot, 5 + 10 + 20);
+        
+        // Action starts after the straddler instead of after the big blind
+        assert_eq!(get_first_player_for_round(&game), 0);
+        
+        // Raises are sized off the straddle
+        assert!(validate_raise(&game, &states[0], 39).is_err());
+        assert!(validate_raise(&game, &states[0], 40).is_ok());
+    }
+    
+    #[test]
+    fn test_straddle_skipped_without_opt_in_or_chips() {
+        let (mut game, mut states) = dealt_in_game(&[500, 500, 500, 15]);
+        game.stage = GameStage::PreFlop;
+        game.straddle_type = StraddleType::UnderTheGun;
+        states[3].straddle_opt_in = true;
+        
+        post_forced_bets(&mut game, &mut states, 1, 2).unwrap();
+        assert_eq!(game.straddle_seat, None);
+        assert_eq!(states[3].chip_stack, 15);
+        assert_eq!(get_first_player_for_round(&game), 3);
+    }
+    
+    #[test]
+    fn test_blinds_passing_sitting_out_seats_are_missed() {
+        let (mut game, mut states) = dealt_in_game(&[100, 100, 100, 100, 100, 0]);
+        // Seat 1 sits out before the small blind, seat 3 between the blinds,
+        // seat 5 is busted
+        game.active_players[1] = false;
+        game.active_players[3] = false;
+        game.active_players[5] = false;
+        
+        // Dealer 0, small blind 2, big blind 4
+        track_missed_blinds(&game, &mut states, 2, 4);
+        assert!(states[1].missed_small_blind && !states[1].missed_big_blind);
+        assert!(!states[3].missed_small_blind && states[3].missed_big_blind);
+        assert!(!states[5].has_missed_blinds());
+        assert!(!states[0].has_missed_blinds() && !states[2].has_missed_blinds());
+    }
+    
+    #[test]
+    fn test_returning_player_posts_missed_blinds() {
+        let (mut game, mut states) = dealt_in_game(&[100, 100, 100, 100]);
+        states[3].missed_small_blind = true;
+        states[3].missed_big_blind = true;
+        
+        post_forced_bets(&mut game, &mut states, 1, 2).unwrap();
+        
+        // The big blind is live, the small blind is dead
+        assert_eq!(states[3].current_bet, 10);
+        assert_eq!(states[3].total_bet_this_hand, 15);
+        assert_eq!(states[3].chip_stack, 85);
+        assert!(!states[3].has_missed_blinds());
+        assert_eq!(game.pot, 5 + 10 + 15);
+        assert_eq!(game.dead_money, 5);
+    }
+    
+    #[test]
+    fn test_missed_blinds_from_the_blinds() {
+        let (mut game, mut states) = dealt_in_game(&[100, 100, 100]);
+        // Returning in the big blind covers everything owed
+        states[2].missed_small_blind = true;
+        states[2].missed_big_blind = true;
+        // Returning in the small blind owes the big blind as dead money
+        states[1].missed_big_blind = true;
+        
+        post_forced_bets(&mut game, &mut states, 1, 2).unwrap();
+        
+        assert_eq!(states[2].total_bet_this_hand, 10);
+        assert!(!states[2].has_missed_blinds());
+        
+        assert_eq!(states[1].current_bet, 5);
+        assert_eq!(states[1].total_bet_this_hand, 15);
+        assert!(!states[1].has_missed_blinds());
+        
+        assert_eq!(game.pot, 15 + 10);
+        assert_eq!(game.dead_money, 10);
+    }
+}
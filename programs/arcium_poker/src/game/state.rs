@@ -1,10 +1,43 @@
 use anchor_lang::prelude::*;
-use crate::types::GameStage;
+use crate::types::{AnteType, GameStage, GameVariant, StraddleType};
 use crate::shared::constants::*;
+use crate::token::conversion::ConversionRate;
+use crate::advanced::rake::RakeConfig;
+
+/// Stakes and limits a table is created with
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GameConfig {
+    /// Unique game ID
+    pub game_id: u64,
+    
+    /// Game authority (creator)
+    pub authority: Pubkey,
+    
+    /// Small blind amount
+    pub small_blind: u64,
+    
+    /// Big blind amount
+    pub big_blind: u64,
+    
+    /// Minimum buy-in
+    pub min_buy_in: u64,
+    
+    /// Maximum buy-in
+    pub max_buy_in: u64,
+    
+    /// Maximum players
+    pub max_players: u8,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
 
 /// Main game account
 #[account]
 pub struct Game {
+    /// Account layout version (GAME_ACCOUNT_VERSION)
+    pub version: u8,
+    
     /// Game authority (creator)
     pub authority: Pubkey,
     
@@ -26,7 +59,7 @@ pub struct Game {
     /// Maximum buy-in
     pub max_buy_in: u64,
     
-    /// Maximum number of players (4-6)
+    /// Maximum number of players (2-10), fixed at creation
     pub max_players: u8,
     
     /// Current number of players
@@ -74,6 +107,108 @@ pub struct Game {
     /// Shuffle session ID from Arcium MPC
     pub shuffle_session_id: [u8; 32],
     
+    /// Poker variant dealt at this table
+    pub variant: GameVariant,
+    
+    /// Short deck option: three of a kind beats a straight
+    pub trips_beat_straights: bool,
+    
+    /// Ante amount (per player for standard antes, total for big blind ante)
+    pub ante: u64,
+    
+    /// Ante format
+    pub ante_type: AnteType,
+    
+    /// Dead money (antes) in the current pot
+    pub dead_money: u64,
+    
+    /// Voluntary straddle allowed at this table
+    pub straddle_type: StraddleType,
+    
+    /// Seat that straddled this hand
+    pub straddle_seat: Option<u8>,
+    
+    /// Per-player ante for bomb pot hands
+    pub bomb_pot_ante: u64,
+    
+    /// Next hand is a bomb pot
+    pub bomb_pot_scheduled: bool,
+    
+    /// Current hand is a bomb pot
+    pub is_bomb_pot: bool,
+    
+    /// Run-it-twice votes from players still in the hand
+    pub run_it_twice_votes: [bool; MAX_PLAYERS],
+    
+    /// All remaining players agreed to run the board twice
+    pub run_it_twice: bool,
+    
+    /// Second runout's community cards (shares the first runout_start cards)
+    pub second_board: [u8; COMMUNITY_CARDS],
+    
+    /// Community cards already revealed when run-it-twice was agreed
+    pub runout_start: u8,
+    
+    /// Hands dealt at this table (current hand number, 0 before the first hand)
+    pub hand_number: u64,
+    
+    /// Actions taken in the current hand; clients echo it to reject stale actions
+    pub action_seq: u64,
+    
+    /// Seconds a player has to act before drawing on their time bank
+    pub turn_timeout: i64,
+    
+    /// Time bank each player starts with (and the most it refills to)
+    pub time_bank_max: i64,
+    
+    /// Seconds added back to each time bank per hand
+    pub time_bank_refill: i64,
+    
+    /// Bounty paid from collected rake to whoever cranks a timed-out player
+    pub crank_fee: u64,
+    
+    /// House rake held in the game escrow
+    pub collected_rake: u64,
+    
+    /// Rake policy applied when a hand settles
+    pub rake: RakeConfig,
+    
+    /// Players dealt into the current hand (sets the rake cap)
+    pub players_dealt: u8,
+    
+    /// Rake taken from the current (or last settled) hand
+    pub hand_rake: u64,
+    
+    /// SPL token mint the table is denominated in (default: SOL table)
+    pub token_mint: Pubkey,
+    
+    /// Escrow token account holding chips on token tables
+    pub token_escrow: Pubkey,
+    
+    /// Bump of the escrow authority PDA
+    pub escrow_bump: u8,
+    
+    /// Chip to token conversion on token tables
+    pub conversion_rate: ConversionRate,
+    
+    /// Refuse deposits when the mint has a freeze authority or unsafe extensions
+    pub reject_unsafe_mint: bool,
+    
+    /// Table has a jackpot pool that must be passed to settlement
+    pub has_jackpot: bool,
+    
+    /// Chips the escrow owes: stacks, rebuy reserves, pot, unswept rake and jackpot pool
+    pub escrowed_chips: u64,
+    
+    /// Fee on partial cash-outs (basis points), added to collected rake
+    pub withdrawal_fee_bps: u16,
+    
+    /// Seconds after leaving during which a rejoin must bring back the previous stack (0 = off)
+    pub rathole_window: i64,
+    
+    /// Recent departures checked against the ratholing window
+    pub recent_exits: [ExitRecord; MAX_PLAYERS],
+    
     /// Game bump seed
     pub bump: u8,
 }
@@ -81,6 +216,7 @@ pub struct Game {
 impl Game {
     /// Calculate space needed for Game account
     pub const LEN: usize = 8 + // discriminator
+        1 + // version
         32 + // authority
         8 + // game_id
         1 + // stage
@@ -104,28 +240,59 @@ impl Game {
         8 + // started_at
         8 + // last_action_at
         32 + // shuffle_session_id
+        1 + // variant
+        1 + // trips_beat_straights
+        8 + // ante
+        1 + // ante_type
+        8 + // dead_money
+        1 + // straddle_type
+        (1 + 1) + // straddle_seat
+        8 + // bomb_pot_ante
+        1 + // bomb_pot_scheduled
+        1 + // is_bomb_pot
+        MAX_PLAYERS + // run_it_twice_votes
+        1 + // run_it_twice
+        COMMUNITY_CARDS + // second_board
+        1 + // runout_start
+        8 + // hand_number
+        8 + // action_seq
+        8 + // turn_timeout
+        8 + // time_bank_max
+        8 + // time_bank_refill
+        8 + // crank_fee
+        8 + // collected_rake
+        RakeConfig::LEN + // rake
+        1 + // players_dealt
+        8 + // hand_rake
+        32 + // token_mint
+        32 + // token_escrow
+        1 + // escrow_bump
+        (8 + 1 + 1) + // conversion_rate
+        1 + // reject_unsafe_mint
+        1 + // has_jackpot
+        8 + // escrowed_chips
+        2 + // withdrawal_fee_bps
+        8 + // rathole_window
+        (ExitRecord::LEN * MAX_PLAYERS) + // recent_exits
         1; // bump
     
     /// Initialize game with default values
-    pub fn new(
-        game_id: u64,
-        authority: Pubkey,
-        small_blind: u64,
-        big_blind: u64,
-        min_buyin: u64,
-        max_buyin: u64,
-        max_players: u8,
-        bump: u8,
-    ) -> Result<Self> {
-        let game = Self {
-            authority,
-            game_id,
+    pub fn new(config: GameConfig) -> Result<Self> {
+        Ok(Self::new_at(config, Clock::get()?.unix_timestamp))
+    }
+    
+    /// Initialize game with default values at a given time
+    pub fn new_at(config: GameConfig, now: i64) -> Self {
+        Self {
+            version: GAME_ACCOUNT_VERSION,
+            authority: config.authority,
+            game_id: config.game_id,
             stage: GameStage::Waiting,
-            small_blind,
-            big_blind,
-            min_buy_in: min_buyin,
-            max_buy_in: max_buyin,
-            max_players,
+            small_blind: config.small_blind,
+            big_blind: config.big_blind,
+            min_buy_in: config.min_buy_in,
+            max_buy_in: config.max_buy_in,
+            max_players: config.max_players,
             player_count: 0,
             players: [Pubkey::default(); MAX_PLAYERS],
             active_players: [false; MAX_PLAYERS],
@@ -139,11 +306,45 @@ impl Game {
             encrypted_deck: [0; 32],
             deck_initialized: false,
             started_at: 0,
-            last_action_at: Clock::get()?.unix_timestamp,
+            last_action_at: now,
             shuffle_session_id: [0; 32],
-            bump,
-        };
-        Ok(game)
+            variant: GameVariant::TexasHoldem,
+            trips_beat_straights: false,
+            ante: 0,
+            ante_type: AnteType::None,
+            dead_money: 0,
+            straddle_type: StraddleType::None,
+            straddle_seat: None,
+            bomb_pot_ante: 0,
+            bomb_pot_scheduled: false,
+            is_bomb_pot: false,
+            run_it_twice_votes: [false; MAX_PLAYERS],
+            run_it_twice: false,
+            second_board: [0; COMMUNITY_CARDS],
+            runout_start: 0,
+            hand_number: 0,
+            action_seq: 0,
+            turn_timeout: TURN_TIMEOUT,
+            time_bank_max: DEFAULT_TIME_BANK,
+            time_bank_refill: DEFAULT_TIME_BANK_REFILL,
+            crank_fee: 0,
+            collected_rake: 0,
+            // Rake is off until the table configures it
+            rake: RakeConfig::disabled(),
+            players_dealt: 0,
+            hand_rake: 0,
+            token_mint: Pubkey::default(),
+            token_escrow: Pubkey::default(),
+            escrow_bump: 0,
+            conversion_rate: ConversionRate::default(),
+            reject_unsafe_mint: false,
+            has_jackpot: false,
+            escrowed_chips: 0,
+            withdrawal_fee_bps: 0,
+            rathole_window: 0,
+            recent_exits: [ExitRecord::default(); MAX_PLAYERS],
+            bump: config.bump,
+        }
     }
     
     /// Check if game is full
@@ -151,48 +352,134 @@ impl Game {
         self.player_count >= self.max_players
     }
     
+    /// Number of seats at the table (seat indices run 0..seat_count)
+    /// Seats are never compacted, so empty seats can sit between players
+    pub fn seat_count(&self) -> u8 {
+        self.max_players
+    }
+    
+    /// Check if a seat is occupied
+    pub fn is_seat_occupied(&self, seat_index: u8) -> bool {
+        self.players[seat_index as usize] != Pubkey::default()
+    }
+    
+    /// Check if the table is denominated in an SPL token rather than SOL
+    pub fn is_token_table(&self) -> bool {
+        self.token_mint != Pubkey::default()
+    }
+    
+    /// Check if a hand is being played
+    pub fn is_hand_in_progress(&self) -> bool {
+        !matches!(self.stage, GameStage::Waiting | GameStage::Finished)
+    }
+    
+    /// Check if a hand won by folds is waiting for its pot to be awarded
+    /// The pot is paid to the last player in the hand when the next hand starts
+    pub fn has_unpaid_pot(&self) -> bool {
+        self.stage == GameStage::Finished && self.pot > 0
+    }
+    
     /// Check if player is in game
     pub fn has_player(&self, player: &Pubkey) -> bool {
-        self.players[..self.player_count as usize]
+        self.players[..self.seat_count() as usize]
             .iter()
             .any(|p| p == player)
     }
     
-    /// Add player to game
+    /// Add player to game at the first empty seat
     pub fn add_player(&mut self, player: Pubkey) -> Result<u8> {
+        let seat_index = (0..self.seat_count())
+            .find(|&seat| !self.is_seat_occupied(seat))
+            .ok_or(crate::shared::PokerError::GameFull)?;
+        
+        self.add_player_at_seat(player, seat_index)?;
+        
+        Ok(seat_index)
+    }
+    
+    /// Add player to game at a chosen empty seat
+    /// Players joining during a hand are dealt in from the next hand
+    pub fn add_player_at_seat(&mut self, player: Pubkey, seat_index: u8) -> Result<()> {
         require!(!self.is_full(), crate::shared::PokerError::GameFull);
         require!(!self.has_player(&player), crate::shared::PokerError::PlayerAlreadyInGame);
+        require!(
+            seat_index < self.seat_count(),
+            crate::shared::PokerError::InvalidSeatPosition
+        );
+        require!(
+            !self.is_seat_occupied(seat_index),
+            crate::shared::PokerError::SeatOccupied
+        );
         
-        let seat_index = self.player_count;
         self.players[seat_index as usize] = player;
-        self.active_players[seat_index as usize] = true;
+        self.active_players[seat_index as usize] = !self.is_hand_in_progress();
         self.player_count += 1;
         
-        Ok(seat_index)
+        Ok(())
     }
     
     /// Remove player from game
+    /// The seat is left empty so other players keep their seat indices
     pub fn remove_player(&mut self, player: &Pubkey) -> Result<()> {
-        let player_index = self.players[..self.player_count as usize]
+        let player_index = self.players[..self.seat_count() as usize]
             .iter()
             .position(|p| p == player)
             .ok_or(crate::shared::PokerError::PlayerNotInGame)?;
         
+        self.players[player_index] = Pubkey::default();
         self.active_players[player_index] = false;
+        self.player_count -= 1;
         
-        // If game hasn't started, we can actually remove them
-        if self.stage == GameStage::Waiting {
-            // Shift players down
-            for i in player_index..(self.player_count as usize - 1) {
-                self.players[i] = self.players[i + 1];
-                self.active_players[i] = self.active_players[i + 1];
-            }
-            self.players[self.player_count as usize - 1] = Pubkey::default();
-            self.active_players[self.player_count as usize - 1] = false;
-            self.player_count -= 1;
+        Ok(())
+    }
+    
+    /// Clear run-it-twice state for a new hand
+    pub fn reset_run_it_twice(&mut self) {
+        self.run_it_twice_votes = [false; MAX_PLAYERS];
+        self.run_it_twice = false;
+        self.second_board = [0; COMMUNITY_CARDS];
+        self.runout_start = 0;
+    }
+    
+    /// Remember a player's stack when they leave, for the ratholing window
+    /// Reuses the player's own record, then an expired one, then the oldest
+    pub fn record_exit(&mut self, player: Pubkey, stack: u64, now: i64) {
+        if self.rathole_window == 0 || stack == 0 {
+            return;
         }
         
-        Ok(())
+        let window = self.rathole_window;
+        let slot = self.recent_exits
+            .iter()
+            .position(|exit| exit.player == player)
+            .or_else(|| self.recent_exits.iter().position(|exit| now - exit.left_at >= window))
+            .unwrap_or_else(|| {
+                (0..MAX_PLAYERS)
+                    .min_by_key(|&i| self.recent_exits[i].left_at)
+                    .unwrap_or(0)
+            });
+        
+        self.recent_exits[slot] = ExitRecord { player, stack, left_at: now };
+    }
+    
+    /// Stack a returning player must buy back in with, if they left within the window
+    pub fn rathole_stack(&self, player: &Pubkey, now: i64) -> Option<u64> {
+        if self.rathole_window == 0 {
+            return None;
+        }
+        
+        self.recent_exits
+            .iter()
+            .find(|exit| exit.player == *player && now - exit.left_at < self.rathole_window)
+            .map(|exit| exit.stack)
+    }
+    
+    /// Hand ranking rules for this table's variant
+    pub fn hand_rules(&self) -> crate::cards::evaluator::HandRules {
+        crate::cards::evaluator::HandRules {
+            short_deck: self.variant == GameVariant::ShortDeck,
+            trips_beat_straights: self.trips_beat_straights,
+        }
     }
     
     /// Get encrypted deck (for dealing cards)
@@ -207,4 +494,16 @@ impl Game {
         // For now, create mock deck structure
         Ok(crate::cards::deck::EncryptedDeck::default())
     }
-}
\ No newline at end of file
+}
+
+/// Stack a player left the table with (ratholing protection)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct ExitRecord {
+    pub player: Pubkey,
+    pub stack: u64,
+    pub left_at: i64,
+}
+
+impl ExitRecord {
+    pub const LEN: usize = 32 + 8 + 8;
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::shared::PokerError;
+use crate::token::{TokenRoute, chips_to_tokens, current_balance, release_tokens_on_leave};
+use crate::security::validate_escrow_balance;
+
+/// Move value sent to the table beyond what it owes to the authority
+/// 
+/// Chips owed to players, the pot, rake and the jackpot stay in the escrow:
+/// only lamports above rent and `escrowed_chips` (or escrow tokens above the
+/// escrowed chips' worth on a token table) are moved. `close_table` refuses
+/// while any surplus remains, so it is swept here first.
+pub fn handler(ctx: Context<crate::SweepSurplus>) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let game_key = game.key();
+    
+    let surplus = match TokenRoute::for_game(
+        game,
+        ctx.accounts.authority_token_account.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )? {
+        Some(route) => {
+            require_keys_eq!(route.player_token_account.owner, game.authority, PokerError::InvalidTokenAccounts);
+            let escrow_authority = ctx.accounts.escrow_authority.as_ref().ok_or(PokerError::InvalidTokenAccounts)?;
+            
+            let owed = chips_to_tokens(game.escrowed_chips, &game.conversion_rate);
+            let surplus = current_balance(route.escrow_token_account)?.saturating_sub(owed);
+            require!(surplus > 0, PokerError::InvalidBetAmount);
+            
+            release_tokens_on_leave(&route, escrow_authority, surplus, game.escrow_bump, game_key)?;
+            surplus
+        }
+        None => {
+            let game_info = game.to_account_info();
+            let rent_exempt = Rent::get()?.minimum_balance(game_info.data_len());
+            let surplus = game_info.lamports().saturating_sub(rent_exempt + game.escrowed_chips);
+            require!(surplus > 0, PokerError::InvalidBetAmount);
+            
+            **game_info.try_borrow_mut_lamports()? -= surplus;
+            **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += surplus;
+            surplus
+        }
+    };
+    
+    validate_escrow_balance(game, ctx.accounts.escrow_token_account.as_ref())?;
+    
+    msg!(
+        "[SURPLUS] Swept {} {} from game {} to authority {} ({} chips still escrowed)",
+        surplus,
+        if game.is_token_table() { "tokens" } else { "lamports" },
+        game.game_id,
+        game.authority,
+        game.escrowed_chips
+    );
+    
+    Ok(())
+}
+
+// Uses the SweepSurplus accounts struct in lib.rs
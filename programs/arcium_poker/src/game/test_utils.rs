@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use super::state::{Game, GameConfig};
+use crate::player::state::PlayerState;
+
+/// A 6-max table at 5/10 blinds with a 100 to 1,000 buy-in
+pub fn test_game() -> Game {
+    Game::new_at(
+        GameConfig {
+            game_id: 1,
+            authority: Pubkey::new_unique(),
+            small_blind: 5,
+            big_blind: 10,
+            min_buy_in: 100,
+            max_buy_in: 1_000,
+            max_players: 6,
+            bump: 0,
+        },
+        0,
+    )
+}
+
+/// `test_game` with a player at each of seats 0.. holding `stacks`
+/// The states are indexed by seat (default at empty seats) and the escrow
+/// holds exactly the seated chips
+pub fn seated_game(stacks: &[u64]) -> (Game, Vec<PlayerState>) {
+    let mut game = test_game();
+    let mut states = vec![PlayerState::default(); game.seat_count() as usize];
+    for (seat, &stack) in stacks.iter().enumerate() {
+        let player = Pubkey::new_unique();
+        game.add_player_at_seat(player, seat as u8).unwrap();
+        states[seat].player = player;
+        states[seat].seat_index = seat as u8;
+        states[seat].chip_stack = stack;
+        game.escrowed_chips += stack;
+    }
+    (game, states)
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 // Module declarations MUST come before declare_id
 pub mod types;
@@ -12,12 +13,15 @@ pub mod showdown;
 pub mod token;
 pub mod security;
 pub mod advanced;
+pub mod treasury;
 
 declare_id!("Cm5y2aab75vj9dpRcyG1EeZNgeh4GZLRkN3BmmRVNEwZ");
 
 // Re-export account state structs for use in Account Context structs below
 pub use game::state::Game;
-pub use player::state::PlayerState;
+pub use player::state::{Bankroll, PlayerRewards, PlayerState};
+pub use treasury::state::{Treasury, TreasuryLedger};
+pub use advanced::jackpot::{Jackpot, JackpotConfig};
 
 #[program]
 pub mod arcium_poker {
@@ -44,79 +48,209 @@ pub mod arcium_poker {
         )
     }
     
+    /// Update table settings (variant, etc.) before the first hand or between hands
+    pub fn configure_table(
+        ctx: Context<ConfigureTable>,
+        params: game::TableConfigParams,
+    ) -> Result<()> {
+        game::configure_handler(ctx, params)
+    }
+    
+    /// Schedule the next hand as a bomb pot
+    pub fn schedule_bomb_pot(ctx: Context<ConfigureTable>) -> Result<()> {
+        game::schedule_bomb_pot(ctx)
+    }
+    
     /// Player joins a game
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
     pub fn join_game(ctx: Context<JoinGame>, buy_in: u64) -> Result<()> {
-        player::join_handler(ctx, buy_in)
+        player::join_handler(ctx, buy_in, None)
+    }
+    
+    /// Player joins a game at a chosen empty seat
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
+    pub fn join_game_at_seat(ctx: Context<JoinGame>, buy_in: u64, seat_index: u8) -> Result<()> {
+        player::join_handler(ctx, buy_in, Some(seat_index))
     }
     
     /// Player leaves a game
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
     pub fn leave_game(ctx: Context<LeaveGame>) -> Result<()> {
         player::leave_handler(ctx)
     }
     
+    /// Cash out part of the stack between hands, less the table's withdrawal fee
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
+    pub fn cash_out(ctx: Context<LeaveGame>, amount: u64) -> Result<()> {
+        player::cash_out_handler(ctx, amount)
+    }
+    
+    /// Player adds chips to their stack between hands
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
+    pub fn top_up(ctx: Context<TopUp>, amount: u64) -> Result<()> {
+        player::top_up_handler(ctx, amount)
+    }
+    
+    /// Player configures auto-rebuy and deposits into the rebuy reserve
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
+    pub fn set_auto_rebuy(ctx: Context<TopUp>, rebuy_amount: u64, deposit: u64) -> Result<()> {
+        player::auto_rebuy_handler(ctx, rebuy_amount, deposit)
+    }
+    
+    /// Player registers a session key for in-hand actions
+    pub fn register_session_key(
+        ctx: Context<ManageSession>,
+        session_key: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        player::register_session_handler(ctx, session_key, expires_at)
+    }
+    
+    /// Player revokes their session key
+    pub fn revoke_session_key(ctx: Context<ManageSession>) -> Result<()> {
+        player::revoke_session_handler(ctx)
+    }
+    
+    /// Open a bankroll that can fund seats at any SOL table
+    pub fn open_bankroll(ctx: Context<OpenBankroll>) -> Result<()> {
+        player::open_bankroll_handler(ctx)
+    }
+    
+    /// Deposit into the player's bankroll
+    pub fn deposit_bankroll(ctx: Context<ManageBankroll>, amount: u64) -> Result<()> {
+        player::deposit_bankroll_handler(ctx, amount)
+    }
+    
+    /// Withdraw from the player's bankroll when not seated
+    pub fn withdraw_bankroll(ctx: Context<ManageBankroll>, amount: u64) -> Result<()> {
+        player::withdraw_bankroll_handler(ctx, amount)
+    }
+    
+    /// Open a rakeback/affiliate rewards account, optionally tagging a referrer
+    pub fn open_rewards(ctx: Context<OpenRewards>, mint: Pubkey) -> Result<()> {
+        player::open_rewards_handler(ctx, mint)
+    }
+    
+    /// Accrue a player's pending rake into rakeback and affiliate balances
+    pub fn accrue_rakeback(ctx: Context<AccrueRakeback>) -> Result<()> {
+        player::accrue_rakeback_handler(ctx)
+    }
+    
+    /// Claim rakeback and affiliate balances from the treasury
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        player::claim_rewards_handler(ctx)
+    }
+    
+    /// Player opts in or out of straddling
+    pub fn set_straddle(ctx: Context<PlayerAction>, enabled: bool) -> Result<()> {
+        player::straddle_handler(ctx, enabled)
+    }
+    
+    /// Sit out from the next hand
+    pub fn sit_out(ctx: Context<PlayerAction>) -> Result<()> {
+        player::sit_out_handler(ctx)
+    }
+    
+    /// Return from sitting out, posting missed blinds or waiting for the big blind
+    pub fn sit_in(ctx: Context<PlayerAction>, post_missed_blinds: bool) -> Result<()> {
+        player::sit_in_handler(ctx, post_missed_blinds)
+    }
+    
+    /// Vote to run the remaining board twice once all players are all-in
+    pub fn vote_run_it_twice(ctx: Context<PlayerAction>, agree: bool) -> Result<()> {
+        showdown::run_it_twice_handler(ctx, agree)
+    }
+    
     /// Start the game - performs Arcium MPC shuffle and deals cards
-    pub fn start_game(
-        ctx: Context<StartGame>,
+    pub fn start_game<'info>(
+        ctx: Context<'_, '_, '_, 'info, StartGame<'info>>,
         player_entropy: Vec<[u8; 32]>,
     ) -> Result<()> {
         game::start_handler(ctx, player_entropy)
     }
     
     /// Player folds their hand
-    pub fn player_fold(ctx: Context<PlayerAction>) -> Result<()> {
-        betting::handle_fold(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
+    pub fn player_fold(ctx: Context<PlayerAction>, hand_number: u64, action_seq: u64) -> Result<()> {
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            types::PlayerActionParam::Fold,
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Player checks (no bet)
-    pub fn player_check(ctx: Context<PlayerAction>) -> Result<()> {
-        betting::handle_check(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
+    pub fn player_check(ctx: Context<PlayerAction>, hand_number: u64, action_seq: u64) -> Result<()> {
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            types::PlayerActionParam::Check,
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Player calls the current bet
-    pub fn player_call(ctx: Context<PlayerAction>) -> Result<()> {
-        betting::handle_call(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
+    pub fn player_call(ctx: Context<PlayerAction>, hand_number: u64, action_seq: u64) -> Result<()> {
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            types::PlayerActionParam::Call,
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Player raises the bet
-    pub fn player_raise(ctx: Context<PlayerAction>, raise_amount: u64) -> Result<()> {
-        betting::handle_raise(&mut ctx.accounts.game, &mut ctx.accounts.player_state, raise_amount)
+    pub fn player_raise(ctx: Context<PlayerAction>, raise_amount: u64, hand_number: u64, action_seq: u64) -> Result<()> {
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            types::PlayerActionParam::Raise { amount: raise_amount },
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Player makes an opening bet
-    pub fn player_bet(ctx: Context<PlayerAction>, bet_amount: u64) -> Result<()> {
-        betting::handle_bet(&mut ctx.accounts.game, &mut ctx.accounts.player_state, bet_amount)
+    pub fn player_bet(ctx: Context<PlayerAction>, bet_amount: u64, hand_number: u64, action_seq: u64) -> Result<()> {
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            types::PlayerActionParam::Bet { amount: bet_amount },
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Player goes all-in
-    pub fn player_all_in(ctx: Context<PlayerAction>) -> Result<()> {
-        betting::handle_all_in(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
+    pub fn player_all_in(ctx: Context<PlayerAction>, hand_number: u64, action_seq: u64) -> Result<()> {
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            types::PlayerActionParam::AllIn,
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Unified player action handler (for easier client integration)
+    /// `hand_number` and `action_seq` must match the game, so stale or
+    /// replayed actions are rejected
     pub fn player_action(
         ctx: Context<PlayerAction>,
         action: types::PlayerActionParam,
+        hand_number: u64,
+        action_seq: u64,
     ) -> Result<()> {
-        match action {
-            types::PlayerActionParam::Fold => {
-                betting::handle_fold(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
-            }
-            types::PlayerActionParam::Check => {
-                betting::handle_check(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
-            }
-            types::PlayerActionParam::Call => {
-                betting::handle_call(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
-            }
-            types::PlayerActionParam::Bet { amount } => {
-                betting::handle_bet(&mut ctx.accounts.game, &mut ctx.accounts.player_state, amount)
-            }
-            types::PlayerActionParam::Raise { amount } => {
-                betting::handle_raise(&mut ctx.accounts.game, &mut ctx.accounts.player_state, amount)
-            }
-            types::PlayerActionParam::AllIn => {
-                betting::handle_all_in(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
-            }
-        }
+        betting::handle_action(
+            &mut ctx.accounts.game,
+            &mut ctx.accounts.player_state,
+            action,
+            hand_number,
+            action_seq,
+        )
     }
     
     /// Advance game to next stage (PreFlop -> Flop -> Turn -> River -> Showdown)
@@ -124,14 +258,81 @@ pub mod arcium_poker {
         game::advance_game_stage(&mut ctx.accounts.game)
     }
     
-    /// Handle player timeout (auto-fold)
+    /// Handle player timeout (auto check-fold)
     pub fn timeout_player(ctx: Context<PlayerAction>) -> Result<()> {
         game::handle_player_timeout(&mut ctx.accounts.game, &mut ctx.accounts.player_state)
     }
     
-    /// Start new hand (after previous hand completes)
-    pub fn new_hand(ctx: Context<NewHand>) -> Result<()> {
-        game::start_new_hand(&mut ctx.accounts.game)
+    /// Check-fold a timed-out player - any signer can crank
+    /// Remaining accounts: PlayerState of every other occupied seat, in any order.
+    pub fn crank_timeout(ctx: Context<CrankTimeout>) -> Result<()> {
+        game::crank_timeout_handler(ctx)
+    }
+    
+    /// Sweep collected rake to the house treasury (authority only)
+    /// Remaining accounts: PlayerState of every occupied seat, in any order.
+    pub fn sweep_rake(ctx: Context<SweepRake>) -> Result<()> {
+        game::sweep_rake_handler(ctx)
+    }
+    
+    /// Sweep lamports or tokens sent to the table beyond what it owes to the authority (authority only)
+    pub fn sweep_surplus(ctx: Context<SweepSurplus>) -> Result<()> {
+        game::sweep_surplus_handler(ctx)
+    }
+    
+    /// Create the table's bad beat / high hand jackpot pool (authority only)
+    pub fn create_jackpot(ctx: Context<CreateJackpot>, config: JackpotConfig) -> Result<()> {
+        advanced::create_jackpot_handler(ctx, config)
+    }
+    
+    /// Update the jackpot qualification and payout rules (authority only)
+    pub fn configure_jackpot(ctx: Context<ConfigureJackpot>, config: JackpotConfig) -> Result<()> {
+        advanced::configure_jackpot_handler(ctx, config)
+    }
+    
+    /// Retire the jackpot pool; its balance returns to collected rake (authority only)
+    pub fn close_jackpot(ctx: Context<CloseJackpot>) -> Result<()> {
+        advanced::close_jackpot_handler(ctx)
+    }
+    
+    /// Close a finished table and its player accounts, returning rent (authority only)
+    pub fn close_table<'info>(ctx: Context<'_, '_, '_, 'info, CloseTable<'info>>) -> Result<()> {
+        game::close_table_handler(ctx)
+    }
+    
+    /// Upgrade a table from the original Game layout (authority only)
+    /// Remaining accounts: the original-layout PlayerState of every seated player (writable)
+    pub fn migrate_game<'info>(ctx: Context<'_, '_, '_, 'info, MigrateGame<'info>>) -> Result<()> {
+        game::migrate_game_handler(ctx)
+    }
+    
+    /// Create the house treasury with an admin and optional M-of-N signer set
+    /// Only the program's upgrade authority may call this; it becomes the admin
+    pub fn initialize_treasury(
+        ctx: Context<InitializeTreasury>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        treasury::initialize_treasury_handler(ctx, signers, threshold)
+    }
+    
+    /// Open the treasury ledger (and vault) for SOL or a token mint
+    pub fn open_treasury_ledger(ctx: Context<OpenTreasuryLedger>, mint: Pubkey) -> Result<()> {
+        treasury::open_treasury_ledger_handler(ctx, mint)
+    }
+    
+    /// Withdraw from the treasury with admin or M-of-N approval
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        treasury::withdraw_treasury_handler(ctx, amount)
+    }
+    
+    /// Start new hand (after previous hand completes) - reshuffles and deals
+    /// Uses the same accounts as start_game
+    pub fn new_hand<'info>(
+        ctx: Context<'_, '_, '_, 'info, StartGame<'info>>,
+        player_entropy: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        game::new_hand_handler(ctx, player_entropy)
     }
     
     /// End the game
@@ -142,29 +343,59 @@ pub mod arcium_poker {
     /// Execute showdown - reveal cards and distribute winnings
     /// Note: This is a simplified version. Full implementation would handle
     /// encrypted card reveals via Arcium MPC
+    /// Remaining accounts: PlayerState accounts for every occupied seat, in any order.
     pub fn execute_showdown(ctx: Context<ExecuteShowdown>) -> Result<()> {
-        // Create pot manager from game state
-        let mut pot_manager = betting::PotManager::new();
-        pot_manager.main_pot = ctx.accounts.game.pot;
-        // Side pots would be calculated from betting history
+        let game_key = ctx.accounts.game.key();
+        let mut seated = player::SeatedPlayers::load(
+            ctx.remaining_accounts,
+            &ctx.accounts.game,
+            &game_key,
+        )?;
+        
+        // Side pots from what each seat put in, antes included
+        let mut pot_manager = betting::PotManager::from_hand(&ctx.accounts.game, &seated.states)?;
+        
+        require!(
+            ctx.accounts.jackpot.is_some() == ctx.accounts.game.has_jackpot,
+            shared::PokerError::InvalidJackpot
+        );
         
-        // Load player states (simplified - would use remaining_accounts in production)
-        let mut player_states = vec![(*ctx.accounts.player_state).clone()];
+        // House rake comes off the pot before it is split; only the rake left
+        // after the jackpot drop reaches the treasury and earns rakeback
+        let rake = advanced::rake_pot(&mut ctx.accounts.game)?;
+        pot_manager.take_rake(rake)?;
+        let drop = ctx.accounts.jackpot.as_mut()
+            .map_or(0, |jackpot| advanced::feed_jackpot(&mut ctx.accounts.game, jackpot));
+        advanced::attribute_rake(rake - drop, &mut seated.states);
         
         showdown::handle_showdown(
             &mut ctx.accounts.game,
-            &mut player_states,
+            &mut seated.states,
             &pot_manager,
         )?;
         
-        // Update player state
-        *ctx.accounts.player_state = player_states[0].clone();
+        if let Some(jackpot) = ctx.accounts.jackpot.as_mut() {
+            advanced::settle_jackpot(&ctx.accounts.game, &mut seated.states, jackpot)?;
+        }
+        
+        security::validate_chip_conservation(
+            &ctx.accounts.game,
+            &seated.states,
+            ctx.accounts.jackpot.as_ref().map_or(0, |jackpot| jackpot.balance),
+        )?;
+        
+        seated.store_all()?;
+        
+        // The signer's account is written again on exit; keep it in sync
+        let seat = ctx.accounts.player_state.seat_index as usize;
+        *ctx.accounts.player_state = seated.states[seat].clone();
         
         Ok(())
     }
     
     /// Initialize computation definition for MPC shuffle
-    /// Must be called once after deployment
+    /// Must be called once after deployment, for SHUFFLE_COMP_DEF_OFFSET and
+    /// again for SHORT_DECK_SHUFFLE_COMP_DEF_OFFSET
     pub fn init_shuffle_comp_def(
         ctx: Context<InitCompDef>,
         comp_def_offset: u32,
@@ -175,7 +406,7 @@ pub mod arcium_poker {
             &ctx.accounts.authority,
             &ctx.accounts.system_program,
             comp_def_offset,
-            0, // shuffle_deck instruction index
+            arcium::integration::shuffle_instruction_index(comp_def_offset)?,
         )
     }
     
@@ -213,12 +444,44 @@ pub struct InitializeGame<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// Mint for a token-denominated table (omit for a SOL table)
+    /// SPL Token and Token-2022 mints are both accepted
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: PDA that owns the escrow token account, holds no data
+    #[account(seeds = [b"token_escrow", game.key().as_ref()], bump)]
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Escrow token account holding chips on a token table
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"escrow_vault", game.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = escrow_authority,
+        token::token_program = token_program
+    )]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ConfigureTable<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    /// Game authority must configure the table
+    #[account(constraint = authority.key() == game.authority @ shared::PokerError::InvalidAction)]
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct StartGame<'info> {
-    #[account(mut)]
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     /// Game authority (creator) must start the game
@@ -260,13 +523,21 @@ pub struct StartGame<'info> {
     
     pub system_program: Program<'info, System>,
     
+    /// Table jackpot pool, fed from uncontested pots (required when the game has one)
+    #[account(
+        mut,
+        seeds = [b"jackpot", game.key().as_ref()],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
     // Remaining accounts: PlayerState accounts for all players in order
     // These will be validated and updated during execution
 }
 
 #[derive(Accounts)]
 pub struct JoinGame<'info> {
-    #[account(mut)]
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     #[account(
@@ -282,16 +553,119 @@ pub struct JoinGame<'info> {
     pub player: Signer<'info>,
     
     pub system_program: Program<'info, System>,
+    
+    /// Player token account (token tables only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Game escrow token account (token tables only)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the table's token (token tables only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Player bankroll funding the seat instead of the wallet (SOL tables only)
+    #[account(
+        mut,
+        seeds = [b"bankroll", player.key().as_ref()],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Option<Account<'info, Bankroll>>,
+    
+    /// Rewards account, created on the player's first join at a table of this mint
+    #[account(
+        init,
+        payer = player,
+        space = PlayerRewards::LEN,
+        seeds = [b"rewards", player.key().as_ref(), game.token_mint.as_ref()],
+        bump
+    )]
+    pub player_rewards: Option<Account<'info, PlayerRewards>>,
+    
+    /// CHECK: Referrer wallet tagged on a new rewards account
+    pub referrer: Option<UncheckedAccount<'info>>,
+    
+    /// Table jackpot pool, counted in the chip conservation check (required when the game has one)
+    #[account(seeds = [b"jackpot", game.key().as_ref()], bump = jackpot.bump)]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    // Remaining accounts: PlayerState of every other occupied seat, for the chip conservation check
 }
 
 #[derive(Accounts)]
 pub struct LeaveGame<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", game.key().as_ref(), player.key().as_ref()],
+        bump = player_state.bump,
+        has_one = game,
+        has_one = player
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Player token account (token tables only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Game escrow token account (token tables only)
     #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the table's token (token tables only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// CHECK: Escrow authority PDA, verified against the game's escrow bump (token tables only)
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Player bankroll funding the seat instead of the wallet (SOL tables only)
+    #[account(
+        mut,
+        seeds = [b"bankroll", player.key().as_ref()],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Option<Account<'info, Bankroll>>,
+    
+    /// CHECK: Player's rewards PDA for the table's mint, verified when rake is pending on leave
+    #[account(mut)]
+    pub player_rewards: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Referrer's rewards account, verified against the player's referrer when an affiliate share is due
+    #[account(mut)]
+    pub referrer_rewards: Option<UncheckedAccount<'info>>,
+    
+    /// Treasury ledger for the table's mint (when pending rake pays rewards)
+    #[account(
+        mut,
+        seeds = [b"treasury_ledger", game.token_mint.as_ref()],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Account<'info, TreasuryLedger>>,
+    
+    /// Table jackpot pool, counted in the chip conservation check (required when the game has one)
+    #[account(seeds = [b"jackpot", game.key().as_ref()], bump = jackpot.bump)]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    // Remaining accounts: PlayerState of every other occupied seat, for the chip conservation check
+}
+
+#[derive(Accounts)]
+pub struct TopUp<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     #[account(
         mut,
-        close = player,
         seeds = [b"player", game.key().as_ref(), player.key().as_ref()],
         bump = player_state.bump,
         has_one = game,
@@ -301,11 +675,159 @@ pub struct LeaveGame<'info> {
     
     #[account(mut)]
     pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Player token account (token tables only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Game escrow token account (token tables only)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the table's token (token tables only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Player bankroll funding the seat instead of the wallet (SOL tables only)
+    #[account(
+        mut,
+        seeds = [b"bankroll", player.key().as_ref()],
+        bump = bankroll.bump
+    )]
+    pub bankroll: Option<Account<'info, Bankroll>>,
+    
+    /// Table jackpot pool, counted in the chip conservation check (required when the game has one)
+    #[account(seeds = [b"jackpot", game.key().as_ref()], bump = jackpot.bump)]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    // Remaining accounts: PlayerState of every other occupied seat, for the chip conservation check
 }
 
 #[derive(Accounts)]
-pub struct PlayerAction<'info> {
+pub struct OpenBankroll<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = Bankroll::LEN,
+        seeds = [b"bankroll", owner.key().as_ref()],
+        bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
     #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageBankroll<'info> {
+    #[account(
+        mut,
+        seeds = [b"bankroll", owner.key().as_ref()],
+        bump = bankroll.bump,
+        has_one = owner
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct OpenRewards<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = PlayerRewards::LEN,
+        seeds = [b"rewards", owner.key().as_ref(), mint.as_ref()],
+        bump
+    )]
+    pub player_rewards: Account<'info, PlayerRewards>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// CHECK: Referrer wallet to tag (optional)
+    pub referrer: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueRakeback<'info> {
+    #[account(constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut, has_one = game)]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        mut,
+        seeds = [b"rewards", player_state.player.as_ref(), game.token_mint.as_ref()],
+        bump = player_rewards.bump
+    )]
+    pub player_rewards: Account<'info, PlayerRewards>,
+    
+    /// Referrer's rewards account (required when the player has a referrer)
+    #[account(mut)]
+    pub referrer_rewards: Option<Account<'info, PlayerRewards>>,
+    
+    /// Treasury ledger for the table's mint, which reserves the accrued rewards
+    #[account(
+        mut,
+        seeds = [b"treasury_ledger", game.token_mint.as_ref()],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Account<'info, TreasuryLedger>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"rewards", owner.key().as_ref(), player_rewards.mint.as_ref()],
+        bump = player_rewards.bump,
+        has_one = owner
+    )]
+    pub player_rewards: Account<'info, PlayerRewards>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury_ledger", player_rewards.mint.as_ref()],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Account<'info, TreasuryLedger>,
+    
+    /// Treasury vault token account (token rewards only)
+    #[account(mut)]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Owner token account (token rewards only)
+    #[account(mut)]
+    pub owner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the rewards token (token rewards only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+pub struct ManageSession<'info> {
+    #[account(constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     #[account(
@@ -317,34 +839,337 @@ pub struct PlayerAction<'info> {
     )]
     pub player_state: Account<'info, PlayerState>,
     
+    /// Only the wallet owner can manage session keys
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PlayerAction<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        has_one = game,
+        constraint = player_state.is_authorized(&player.key()) @ shared::PokerError::UnauthorizedSigner
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    /// Player wallet, or the player's session key
     #[account(mut)]
     pub player: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct AdvanceStage<'info> {
+pub struct CrankTimeout<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    /// Player whose turn has timed out
+    #[account(mut, has_one = game)]
+    pub player_state: Account<'info, PlayerState>,
+    
+    /// Anyone can crank a stalled table
     #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    /// Cranker token account for the bounty (token tables only)
+    #[account(mut)]
+    pub player_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Game escrow token account (token tables only)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the table's token (token tables only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// CHECK: Escrow authority PDA, verified against the game's escrow bump (token tables only)
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Table jackpot pool, counted in the chip conservation check (required when the game has one)
+    #[account(seeds = [b"jackpot", game.key().as_ref()], bump = jackpot.bump)]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    // Remaining accounts: PlayerState of every other occupied seat, for the chip conservation check
+}
+
+#[derive(Accounts)]
+pub struct SweepRake<'info> {
+    #[account(mut, has_one = authority, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
-    /// Any player or authority can advance the stage
-    pub signer: Signer<'info>,
+    pub authority: Signer<'info>,
     
-    // Remaining accounts: PlayerState accounts for all players
+    /// House treasury receiving the rake (holds SOL rake)
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Treasury ledger for the table's mint (default mint for SOL tables)
+    #[account(
+        mut,
+        seeds = [b"treasury_ledger", game.token_mint.as_ref()],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Account<'info, TreasuryLedger>,
+    
+    /// Treasury vault token account (token tables only)
+    #[account(mut)]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Game escrow token account (token tables only)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the table's token (token tables only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// CHECK: Escrow authority PDA, verified against the game's escrow bump (token tables only)
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Table jackpot pool, counted in the chip conservation check (required when the game has one)
+    #[account(seeds = [b"jackpot", game.key().as_ref()], bump = jackpot.bump)]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    // Remaining accounts: PlayerState of every occupied seat, for the chip conservation check
 }
 
 #[derive(Accounts)]
-pub struct NewHand<'info> {
+pub struct SweepSurplus<'info> {
+    #[account(mut, has_one = authority, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
     #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Game escrow token account (token tables only)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Authority token account receiving the surplus tokens (token tables only)
+    #[account(mut)]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the table's token (token tables only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// CHECK: Escrow authority PDA, signs the release with the game's escrow bump (token tables only)
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateJackpot<'info> {
+    #[account(mut, has_one = authority, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
-    /// Game authority must start new hand
-    #[account(constraint = authority.key() == game.authority @ shared::PokerError::InvalidAction)]
+    #[account(
+        init,
+        payer = authority,
+        space = Jackpot::LEN,
+        seeds = [b"jackpot", game.key().as_ref()],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+    
+    #[account(mut)]
     pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct EndGame<'info> {
+pub struct ConfigureJackpot<'info> {
+    #[account(has_one = authority, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        seeds = [b"jackpot", game.key().as_ref()],
+        bump = jackpot.bump,
+        has_one = game
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseJackpot<'info> {
+    #[account(mut, has_one = authority, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    #[account(
+        mut,
+        seeds = [b"jackpot", game.key().as_ref()],
+        bump = jackpot.bump,
+        has_one = game,
+        close = authority
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTable<'info> {
+    #[account(mut, has_one = authority, close = authority, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Game escrow token account (token tables only)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// CHECK: Escrow authority PDA, signs the escrow close with the game's escrow bump (token tables only)
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Treasury ledger for the table's mint (when pending rake pays rewards)
+    #[account(
+        mut,
+        seeds = [b"treasury_ledger", game.token_mint.as_ref()],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Account<'info, TreasuryLedger>>,
+    
+    // Remaining accounts, for every PlayerState of the game: [PlayerState, player wallet (mut),
+    // bankroll if seat was bankroll-funded, rewards PDA (mut) and referrer rewards (mut) if rake is pending]
+}
+
+#[derive(Accounts)]
+pub struct MigrateGame<'info> {
+    /// CHECK: Game account in the original layout, verified against its PDA and discriminator in the handler
+    #[account(mut)]
+    pub game: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// This program, to locate its ProgramData account
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ shared::PokerError::UnauthorizedSigner)]
+    pub program: Program<'info, crate::program::ArciumPoker>,
+    
+    /// Only the program's upgrade authority may create the treasury
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ shared::PokerError::UnauthorizedSigner)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct OpenTreasuryLedger<'info> {
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = TreasuryLedger::LEN,
+        seeds = [b"treasury_ledger", mint.as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, TreasuryLedger>,
+    
     #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Mint of the ledger's token (omit for the SOL ledger)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// Vault token account owned by the treasury (omit for the SOL ledger)
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"treasury_vault", mint.as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = treasury,
+        token::token_program = token_program
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury_ledger", ledger.mint.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, TreasuryLedger>,
+    
+    /// Admin, or one member of the signer set
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Destination wallet chosen by the approvers
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Treasury vault token account (token ledgers only)
+    #[account(mut)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Recipient token account (token ledgers only)
+    #[account(mut)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Mint of the ledger's token (token ledgers only)
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    // Remaining accounts: co-signers from the treasury signer set
+}
+
+#[derive(Accounts)]
+pub struct AdvanceStage<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
+    pub game: Account<'info, Game>,
+    
+    /// Any player or authority can advance the stage
+    pub signer: Signer<'info>,
+    
+    // Remaining accounts: PlayerState accounts for all players
+}
+
+#[derive(Accounts)]
+pub struct EndGame<'info> {
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     /// Game authority must end the game
@@ -354,7 +1179,7 @@ pub struct EndGame<'info> {
 
 #[derive(Accounts)]
 pub struct ExecuteShowdown<'info> {
-    #[account(mut)]
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     #[account(
@@ -368,6 +1193,14 @@ pub struct ExecuteShowdown<'info> {
     
     pub player: Signer<'info>,
     
+    /// Table jackpot pool (required when the game has one)
+    #[account(
+        mut,
+        seeds = [b"jackpot", game.key().as_ref()],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
     // Remaining accounts: Other PlayerState accounts for all players in showdown
 }
 
@@ -391,7 +1224,7 @@ pub struct InitCompDef<'info> {
 
 #[derive(Accounts)]
 pub struct MxeCallback<'info> {
-    #[account(mut)]
+    #[account(mut, constraint = game.version == shared::constants::GAME_ACCOUNT_VERSION @ shared::PokerError::GameAccountOutdated)]
     pub game: Account<'info, Game>,
     
     /// MXE program calling back
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use super::state::Bankroll;
+use crate::game::state::Game;
+use crate::shared::PokerError;
+
+/// Open the player's cross-table bankroll
+pub fn open(ctx: Context<crate::OpenBankroll>) -> Result<()> {
+    let bankroll = &mut ctx.accounts.bankroll;
+    
+    bankroll.owner = ctx.accounts.owner.key();
+    bankroll.balance = 0;
+    bankroll.seated_tables = 0;
+    bankroll.bump = ctx.bumps.bankroll;
+    
+    msg!("[BANKROLL] Opened bankroll for {}", bankroll.owner);
+    
+    Ok(())
+}
+
+/// Deposit lamports from the owner's wallet into the bankroll
+pub fn deposit(ctx: Context<crate::ManageBankroll>, amount: u64) -> Result<()> {
+    require!(amount > 0, PokerError::InvalidBetAmount);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.bankroll.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let bankroll = &mut ctx.accounts.bankroll;
+    bankroll.credit(amount);
+    
+    msg!("[BANKROLL] {} deposited {} (balance: {})", bankroll.owner, amount, bankroll.balance);
+    
+    Ok(())
+}
+
+/// Withdraw from the bankroll to the owner's wallet
+///
+/// Only allowed once the owner has left every table funded by the bankroll.
+pub fn withdraw(ctx: Context<crate::ManageBankroll>, amount: u64) -> Result<()> {
+    let bankroll = &mut ctx.accounts.bankroll;
+    
+    take_withdrawal(bankroll, amount)?;
+    
+    // Rent stays in the account; only the tracked balance can leave
+    **bankroll.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    msg!("[BANKROLL] {} withdrew {} (balance: {})", bankroll.owner, amount, bankroll.balance);
+    
+    Ok(())
+}
+
+/// Take a withdrawal off the bankroll balance
+/// Refused while the bankroll still funds a seat
+pub(crate) fn take_withdrawal(bankroll: &mut Bankroll, amount: u64) -> Result<()> {
+    require!(bankroll.seated_tables == 0, PokerError::BankrollInUse);
+    bankroll.debit(amount)
+}
+
+/// Move chips from a bankroll into the game escrow
+/// Bankrolls hold lamports, so only SOL tables can be funded from them
+pub(crate) fn debit_bankroll<'info>(
+    game: &mut Account<'info, Game>,
+    bankroll: &mut Account<'info, Bankroll>,
+    chips: u64,
+) -> Result<()> {
+    require!(!game.is_token_table(), PokerError::InvalidBankroll);
+    
+    bankroll.debit(chips)?;
+    **bankroll.to_account_info().try_borrow_mut_lamports()? -= chips;
+    **game.to_account_info().try_borrow_mut_lamports()? += chips;
+    game.escrowed_chips += chips;
+    
+    Ok(())
+}
+
+/// Move chips from the game escrow back into a bankroll
+pub(crate) fn credit_bankroll<'info>(
+    game: &mut Account<'info, Game>,
+    bankroll: &mut Account<'info, Bankroll>,
+    chips: u64,
+) -> Result<()> {
+    game.escrowed_chips = game.escrowed_chips
+        .checked_sub(chips)
+        .ok_or(PokerError::EscrowImbalance)?;
+    **game.to_account_info().try_borrow_mut_lamports()? -= chips;
+    **bankroll.to_account_info().try_borrow_mut_lamports()? += chips;
+    bankroll.credit(chips);
+    
+    Ok(())
+}
+
+// Uses the OpenBankroll and ManageBankroll accounts structs in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_bankroll_balance_and_withdrawal() {
+        let mut bankroll = Bankroll { balance: 1_000, ..Default::default() };
+        
+        bankroll.debit(400).unwrap();
+        assert_eq!(bankroll.balance, 600);
+        
+        // Cannot spend more than the balance
+        assert!(bankroll.debit(601).is_err());
+        assert_eq!(bankroll.balance, 600);
+        
+        bankroll.credit(150);
+        assert_eq!(bankroll.balance, 750);
+        
+        // Locked while a seat is funded from it
+        bankroll.seated_tables = 1;
+        assert!(take_withdrawal(&mut bankroll, 100).is_err());
+        assert_eq!(bankroll.balance, 750);
+        
+        bankroll.seated_tables = 0;
+        assert!(take_withdrawal(&mut bankroll, 751).is_err());
+        take_withdrawal(&mut bankroll, 750).unwrap();
+        assert_eq!(bankroll.balance, 0);
+    }
+}
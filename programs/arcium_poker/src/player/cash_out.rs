@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::game::state::Game;
+use crate::types::PlayerStatus;
+use crate::shared::PokerError;
+use crate::token::{TokenRoute, payout_chips, validate_withdrawal, calculate_withdrawal_fee};
+use crate::security::validate_escrow_invariants;
+use super::bankroll::credit_bankroll;
+use super::state::PlayerState;
+
+/// Cash out part of a player's stack between hands
+///
+/// The table's withdrawal fee stays in escrow as collected rake and is swept
+/// to the treasury with it; the rest goes to the bankroll that funded the
+/// seat, or to the wallet. Refused while ratholing protection is on, since
+/// taking chips off the table is what it guards against.
+pub fn handler(ctx: Context<crate::LeaveGame>, amount: u64) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let player_state = &mut ctx.accounts.player_state;
+    
+    validate_cash_out(game, player_state, amount)?;
+    
+    // A seat bought from the bankroll cashes out to it
+    require!(
+        !player_state.funded_by_bankroll || ctx.accounts.bankroll.is_some(),
+        PokerError::InvalidBankroll
+    );
+    
+    let (net_amount, fee) = take_cash_out(game, player_state, amount);
+    
+    if net_amount > 0 {
+        match ctx.accounts.bankroll.as_mut() {
+            Some(bankroll) if player_state.funded_by_bankroll => {
+                credit_bankroll(game, bankroll, net_amount)?;
+            }
+            _ => {
+                let route = TokenRoute::for_game(
+                    game,
+                    ctx.accounts.player_token_account.as_ref(),
+                    ctx.accounts.escrow_token_account.as_ref(),
+                    ctx.accounts.token_mint.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                )?;
+                payout_chips(
+                    game,
+                    &ctx.accounts.player.to_account_info(),
+                    ctx.accounts.escrow_authority.as_ref().map(|authority| authority.as_ref()),
+                    route,
+                    net_amount,
+                )?;
+            }
+        }
+    }
+    
+    validate_escrow_invariants(
+        game,
+        ctx.remaining_accounts,
+        Some(player_state),
+        ctx.accounts.jackpot.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+    )?;
+    
+    msg!(
+        "Player {} cashed out {} chips ({} fee, stack: {})",
+        player_state.player,
+        net_amount,
+        fee,
+        player_state.chip_stack
+    );
+    
+    Ok(())
+}
+
+/// Check a cash out request against the table and the player's stack
+pub(crate) fn validate_cash_out(game: &Game, player_state: &PlayerState, amount: u64) -> Result<()> {
+    require!(amount > 0, PokerError::InvalidBetAmount);
+    require!(
+        player_state.status != PlayerStatus::Left,
+        PokerError::PlayerNotInGame
+    );
+    require!(game.rathole_window == 0, PokerError::RatholingNotAllowed);
+    validate_withdrawal(player_state, game, amount)
+}
+
+/// Take the chips off the player's stack, keeping the fee as collected rake
+/// Returns (chips paid out, fee)
+pub(crate) fn take_cash_out(game: &mut Game, player_state: &mut PlayerState, amount: u64) -> (u64, u64) {
+    let (net_amount, fee) = calculate_withdrawal_fee(amount, game.withdrawal_fee_bps);
+    player_state.chip_stack -= amount;
+    game.collected_rake += fee;
+    (net_amount, fee)
+}
+
+// Uses the LeaveGame accounts struct in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::seated_game;
+    use crate::types::GameStage;
+    
+    fn seated(stack: u64) -> (Game, PlayerState) {
+        let (game, states) = seated_game(&[stack]);
+        (game, states[0].clone())
+    }
+    
+    #[test]
+    fn test_cash_out_fee_split() {
+        let (mut game, mut player_state) = seated(1_000);
+        game.withdrawal_fee_bps = 250;
+        
+        let (net_amount, fee) = take_cash_out(&mut game, &mut player_state, 400);
+        assert_eq!((net_amount, fee), (390, 10));
+        assert_eq!(player_state.chip_stack, 600);
+        assert_eq!(game.collected_rake, 10);
+        
+        // No fee configured: everything goes to the player
+        game.withdrawal_fee_bps = 0;
+        assert_eq!(take_cash_out(&mut game, &mut player_state, 100), (100, 0));
+        assert_eq!(game.collected_rake, 10);
+    }
+    
+    #[test]
+    fn test_cash_out_after_showdown() {
+        let (mut game, mut player_state) = seated(500);
+        game.stage = GameStage::Finished;
+        player_state.has_cards = true;
+        
+        assert!(validate_cash_out(&game, &player_state, 500).is_ok());
+        assert!(validate_cash_out(&game, &player_state, 501).is_err());
+        assert!(validate_cash_out(&game, &player_state, 0).is_err());
+        
+        game.stage = GameStage::Flop;
+        assert!(validate_cash_out(&game, &player_state, 100).is_err());
+    }
+    
+    #[test]
+    fn test_cash_out_refused_with_rathole_protection() {
+        let (mut game, player_state) = seated(500);
+        game.rathole_window = 3_600;
+        
+        assert!(validate_cash_out(&game, &player_state, 100).is_err());
+    }
+    
+    #[test]
+    fn test_rathole_stack_within_window() {
+        let (mut game, _) = seated(0);
+        let player = Pubkey::new_unique();
+        
+        // Protection off: nothing is remembered
+        game.record_exit(player, 800, 1_000);
+        assert_eq!(game.rathole_stack(&player, 1_000), None);
+        
+        game.rathole_window = 3_600;
+        game.record_exit(player, 800, 1_000);
+        assert_eq!(game.rathole_stack(&player, 1_000 + 3_599), Some(800));
+        assert_eq!(game.rathole_stack(&player, 1_000 + 3_600), None);
+        assert_eq!(game.rathole_stack(&Pubkey::new_unique(), 1_000), None);
+        
+        // Leaving again replaces the player's own record
+        game.record_exit(player, 300, 2_000);
+        assert_eq!(game.rathole_stack(&player, 2_000), Some(300));
+        assert_eq!(
+            game.recent_exits.iter().filter(|exit| exit.player == player).count(),
+            1
+        );
+        
+        // Busting out leaves nothing to protect
+        let busted = Pubkey::new_unique();
+        game.record_exit(busted, 0, 2_000);
+        assert_eq!(game.rathole_stack(&busted, 2_000), None);
+    }
+}
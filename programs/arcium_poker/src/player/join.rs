@@ -1,26 +1,45 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
 use super::state::PlayerState;
+use super::bankroll::debit_bankroll;
 use crate::game::state::Game;
-use crate::types::GameStage;
-use crate::shared::{PokerError, validate_buy_in};
+use crate::shared::{validate_buy_in, PokerError};
+use crate::token::{TokenRoute, deposit_chips};
+use crate::security::validate_escrow_invariants;
 
 /// Player joins a poker game
-pub fn handler(ctx: Context<crate::JoinGame>, buy_in: u64) -> Result<()> {
+/// 
+/// Takes the chosen seat, or the first empty seat when none is given.
+/// Players joining a running table are dealt in from the next hand.
+pub fn handler(ctx: Context<crate::JoinGame>, buy_in: u64, seat_index: Option<u8>) -> Result<()> {
     let game = &mut ctx.accounts.game;
     let player_state = &mut ctx.accounts.player_state;
     
-    // Validate game state
-    require!(
-        game.stage == GameStage::Waiting,
-        PokerError::GameAlreadyStarted
-    );
-    
-    // Validate buy-in amount
-    validate_buy_in(buy_in, game.min_buy_in, game.max_buy_in)?;
+    // Validate buy-in amount; a player back within the ratholing window must
+    // bring at least the stack they left with, even above the max buy-in
+    match game.rathole_stack(&ctx.accounts.player.key(), Clock::get()?.unix_timestamp) {
+        Some(previous_stack) => {
+            msg!("[RATHOLE] Returning player must buy in with at least {}", previous_stack);
+            validate_buy_in(
+                buy_in,
+                game.min_buy_in.max(previous_stack),
+                game.max_buy_in.max(previous_stack),
+            )?;
+        }
+        None => validate_buy_in(buy_in, game.min_buy_in, game.max_buy_in)?,
+    }
     
     // Add player to game and get seat index
-    let seat_index = game.add_player(ctx.accounts.player.key())?;
+    let seat_index = match seat_index {
+        Some(seat_index) => {
+            game.add_player_at_seat(ctx.accounts.player.key(), seat_index)?;
+            seat_index
+        }
+        None => game.add_player(ctx.accounts.player.key())?,
+    };
+    
+    if game.is_hand_in_progress() {
+        msg!("Hand in progress - seat {} is dealt in next hand", seat_index);
+    }
     
     // Store values we need for later (before transfers)
     let game_key = game.key();
@@ -29,33 +48,70 @@ pub fn handler(ctx: Context<crate::JoinGame>, buy_in: u64) -> Result<()> {
     let max_players = game.max_players;
     let player_key = ctx.accounts.player.key();
     
-    // Transfer buy-in to game escrow (game PDA)
-    transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.player.to_account_info(),
-                to: game.to_account_info(),
-            },
-        ),
-        buy_in,
-    )?;
+    // Buy in from the player's bankroll, or transfer from the wallet to game
+    // escrow (lamports, or tokens on a token table)
+    // Transfer-fee mints credit only the chips that reached the escrow
+    let chips = match ctx.accounts.bankroll.as_mut() {
+        Some(bankroll) => {
+            debit_bankroll(game, bankroll, buy_in)?;
+            bankroll.seated_tables += 1;
+            buy_in
+        }
+        None => {
+            let route = TokenRoute::for_game(
+                game,
+                ctx.accounts.player_token_account.as_ref(),
+                ctx.accounts.escrow_token_account.as_ref(),
+                ctx.accounts.token_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            )?;
+            deposit_chips(
+                game,
+                &ctx.accounts.player,
+                &ctx.accounts.system_program,
+                route,
+                buy_in,
+            )?
+        }
+    };
     
     // Initialize player state
     player_state.initialize(
         player_key,
         game_key,
         seat_index,
-        buy_in,
+        chips,
         ctx.bumps.player_state,
     );
+    player_state.time_bank = game.time_bank_max;
+    player_state.funded_by_bankroll = ctx.accounts.bankroll.is_some();
+    
+    validate_escrow_invariants(
+        game,
+        ctx.remaining_accounts,
+        Some(player_state),
+        ctx.accounts.jackpot.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+    )?;
+    
+    // First join: open the player's rewards account and tag their referrer
+    if let Some(player_rewards) = ctx.accounts.player_rewards.as_mut() {
+        let referrer = ctx.accounts.referrer.as_ref().map_or(Pubkey::default(), |referrer| referrer.key());
+        player_rewards.initialize(
+            player_key,
+            game.token_mint,
+            referrer,
+            ctx.bumps.player_rewards.ok_or(PokerError::InvalidReferrer)?,
+        )?;
+        msg!("Player {} referred by {}", player_key, referrer);
+    }
     
     msg!(
         "Player {} joined game {} at seat {} with {} chips",
         player_key,
         game_id,
         seat_index,
-        buy_in
+        chips
     );
     msg!("Players in game: {}/{}", player_count, max_players);
     
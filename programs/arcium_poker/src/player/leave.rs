@@ -1,19 +1,27 @@
 use anchor_lang::prelude::*;
-use super::state::PlayerState;
+use crate::game::flow::{advance_to_next_active_player, check_single_player_remaining};
 use crate::game::state::Game;
-use crate::types::GameStage;
+use crate::player::state::PlayerState;
+use crate::types::{GameStage, PlayerStatus};
 use crate::shared::PokerError;
+use crate::token::{TokenRoute, payout_chips};
+use crate::security::validate_escrow_invariants;
+use super::bankroll::credit_bankroll;
+use super::rewards::accrue_before_close;
 
 /// Player leaves a poker game
+///
+/// Between hands the seat is emptied and the PlayerState account is closed.
+/// The other players keep their seat indices.
+/// During a hand the player forfeits: they fold (unless all-in), their
+/// committed chips stay in the pot and the rest of their stack is cashed out
+/// now. The seat is freed when the next hand starts.
+/// Seats bought from the bankroll cash out to it instead of the wallet, and
+/// the bankroll's seat is released on either path. Rake the player has paid
+/// is accrued to their rewards before they go.
 pub fn handler(ctx: Context<crate::LeaveGame>) -> Result<()> {
     let game = &mut ctx.accounts.game;
-    let player_state = &ctx.accounts.player_state;
-    
-    // Cannot leave during active hand (after PreFlop starts)
-    require!(
-        game.stage == GameStage::Waiting || game.stage == GameStage::Finished,
-        PokerError::CannotLeaveDuringHand
-    );
+    let player_state = &mut ctx.accounts.player_state;
     
     // Verify player is in this game
     require!(
@@ -21,19 +29,69 @@ pub fn handler(ctx: Context<crate::LeaveGame>) -> Result<()> {
         PokerError::PlayerNotInGame
     );
     
-    // Remove player from game
-    game.remove_player(&ctx.accounts.player.key())?;
+    // A seat bought from the bankroll cashes out to it
+    require!(
+        !player_state.funded_by_bankroll || ctx.accounts.bankroll.is_some(),
+        PokerError::InvalidBankroll
+    );
     
-    // Return remaining chips to player
-    let remaining_chips = player_state.chip_stack;
+    validate_can_leave(game, player_state)?;
+    
+    let mid_hand = game.is_hand_in_progress();
+    if mid_hand {
+        forfeit_hand(game, player_state)?;
+    } else if game.has_player(&ctx.accounts.player.key()) {
+        // Remove player from game
+        game.remove_player(&ctx.accounts.player.key())?;
+    }
+    
+    // Ratholing protection remembers the stack taken off the table
+    game.record_exit(
+        ctx.accounts.player.key(),
+        player_state.chip_stack,
+        Clock::get()?.unix_timestamp,
+    );
+    
+    // Return remaining chips and any unused rebuy reserve to player
+    let remaining_chips = player_state.chip_stack + player_state.rebuy_reserve;
     if remaining_chips > 0 {
-        // Transfer lamports from game PDA to player
-        **game.to_account_info().try_borrow_mut_lamports()? -= remaining_chips;
-        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += remaining_chips;
+        // Pay out to the bankroll that funded the seat, or from game escrow to
+        // the wallet (lamports, or tokens on a token table)
+        match ctx.accounts.bankroll.as_mut() {
+            Some(bankroll) if player_state.funded_by_bankroll => {
+                credit_bankroll(game, bankroll, remaining_chips)?;
+            }
+            _ => {
+                let route = TokenRoute::for_game(
+                    game,
+                    ctx.accounts.player_token_account.as_ref(),
+                    ctx.accounts.escrow_token_account.as_ref(),
+                    ctx.accounts.token_mint.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                )?;
+                payout_chips(
+                    game,
+                    &ctx.accounts.player.to_account_info(),
+                    ctx.accounts.escrow_authority.as_ref().map(|authority| authority.as_ref()),
+                    route,
+                    remaining_chips,
+                )?;
+            }
+        }
+        player_state.chip_stack = 0;
+        player_state.rebuy_reserve = 0;
         
         msg!("Returned {} chips to player", remaining_chips);
     }
     
+    validate_escrow_invariants(
+        game,
+        ctx.remaining_accounts,
+        Some(player_state),
+        ctx.accounts.jackpot.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+    )?;
+    
     msg!(
         "Player {} left game {}",
         ctx.accounts.player.key(),
@@ -41,7 +99,145 @@ pub fn handler(ctx: Context<crate::LeaveGame>) -> Result<()> {
     );
     msg!("Players remaining: {}", game.player_count);
     
+    accrue_before_close(
+        game,
+        player_state,
+        ctx.accounts.player_rewards.as_ref().map(|rewards| rewards.as_ref()),
+        ctx.accounts.referrer_rewards.as_ref().map(|rewards| rewards.as_ref()).into_iter(),
+        ctx.accounts.treasury_ledger.as_deref_mut(),
+    )?;
+    
+    // The bankroll stops counting this table on either path; chips won after
+    // a mid-hand leave cash out to the wallet
+    if let Some(bankroll) = ctx.accounts.bankroll.as_mut().filter(|_| player_state.funded_by_bankroll) {
+        bankroll.seated_tables = bankroll.seated_tables.saturating_sub(1);
+        player_state.funded_by_bankroll = false;
+    }
+    
+    // Keep the account until the hand is settled and the seat is freed
+    if !mid_hand {
+        ctx.accounts.player_state.close(ctx.accounts.player.to_account_info())?;
+    }
+    
+    Ok(())
+}
+
+/// The last player in a hand won by folds stays seated until new_hand pays them
+/// the pot; leaving first would close their account and strand the pot
+pub(crate) fn validate_can_leave(game: &Game, player_state: &PlayerState) -> Result<()> {
+    let seat = player_state.seat_index as usize;
+    require!(
+        !(game.has_unpaid_pot() && game.active_players[seat] && !player_state.has_folded),
+        PokerError::CannotLeaveDuringHand
+    );
     Ok(())
 }
 
-// LeaveGame struct moved to lib.rs at crate root (required by Anchor)
\ No newline at end of file
+/// Take a player out of the hand in progress
+pub(crate) fn forfeit_hand(game: &mut Game, player_state: &mut PlayerState) -> Result<()> {
+    require!(
+        player_state.status != PlayerStatus::Left,
+        PokerError::CannotLeaveDuringHand
+    );
+    
+    let seat = player_state.seat_index;
+    let in_hand = game.active_players[seat as usize] && !player_state.has_folded;
+    
+    // An all-in player stays in the hand and can still win at showdown
+    if in_hand && !player_state.is_all_in {
+        player_state.has_folded = true;
+        game.active_players[seat as usize] = false;
+        
+        msg!("Player {} folded on leaving", player_state.player);
+        
+        // Actions built before the turn moved or the hand ended are now stale
+        if check_single_player_remaining(game) {
+            game.stage = GameStage::Finished;
+            game.action_seq += 1;
+            msg!("Only one player remaining, hand complete");
+        } else if game.current_player_index == seat {
+            advance_to_next_active_player(game)?;
+            game.action_seq += 1;
+        }
+    }
+    
+    player_state.status = PlayerStatus::Left;
+    
+    Ok(())
+}
+
+// LeaveGame struct moved to lib.rs at crate root (required by Anchor)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::seated_game;
+    
+    /// Seats 0.. with 100 chips each, preflop with seat 0 to act
+    fn hand_in_progress(seats: usize) -> (Game, Vec<PlayerState>) {
+        let (mut game, states) = seated_game(&vec![100; seats]);
+        game.stage = GameStage::PreFlop;
+        game.current_player_index = 0;
+        (game, states)
+    }
+    
+    #[test]
+    fn test_leave_between_hands_keeps_other_seats() {
+        let (mut game, states) = hand_in_progress(3);
+        game.stage = GameStage::Waiting;
+        
+        game.remove_player(&states[1].player).unwrap();
+        assert_eq!(game.player_count, 2);
+        assert_eq!(game.players[0], states[0].player);
+        assert_eq!(game.players[1], Pubkey::default());
+        assert_eq!(game.players[2], states[2].player);
+        
+        // A newcomer can take the freed seat, but not an occupied one
+        let newcomer = Pubkey::new_unique();
+        assert!(game.add_player_at_seat(newcomer, 2).is_err());
+        game.add_player_at_seat(newcomer, 1).unwrap();
+        assert_eq!(game.players[1], newcomer);
+    }
+    
+    #[test]
+    fn test_mid_hand_leave_folds_and_keeps_seat() {
+        let (mut game, mut states) = hand_in_progress(3);
+        
+        forfeit_hand(&mut game, &mut states[1]).unwrap();
+        assert!(states[1].has_folded);
+        assert_eq!(states[1].status, PlayerStatus::Left);
+        assert!(!game.active_players[1]);
+        assert_eq!(game.stage, GameStage::PreFlop);
+        
+        // The seat stays taken until new_hand frees it
+        assert_eq!(game.players[1], states[1].player);
+        assert_eq!(game.player_count, 3);
+        
+        // Leaving twice is refused
+        assert!(forfeit_hand(&mut game, &mut states[1]).is_err());
+    }
+    
+    #[test]
+    fn test_all_in_leaver_stays_in_hand() {
+        let (mut game, mut states) = hand_in_progress(2);
+        states[1].is_all_in = true;
+        
+        forfeit_hand(&mut game, &mut states[1]).unwrap();
+        assert!(!states[1].has_folded);
+        assert!(game.active_players[1]);
+        assert_eq!(states[1].status, PlayerStatus::Left);
+    }
+    
+    #[test]
+    fn test_heads_up_leave_ends_hand() {
+        let (mut game, mut states) = hand_in_progress(2);
+        game.pot = 30;
+        
+        forfeit_hand(&mut game, &mut states[1]).unwrap();
+        assert_eq!(game.stage, GameStage::Finished);
+        
+        // The winner waits for new_hand to pay the pot; the leaver may go
+        assert!(validate_can_leave(&game, &states[0]).is_err());
+        assert!(validate_can_leave(&game, &states[1]).is_ok());
+    }
+}
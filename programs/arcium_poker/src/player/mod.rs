@@ -2,11 +2,33 @@ pub mod state;
 pub mod join;
 pub mod leave;
 pub mod actions;
+pub mod straddle;
+pub mod sit_out;
+pub mod top_up;
+pub mod cash_out;
+pub mod session;
+pub mod bankroll;
+pub mod rewards;
 
 pub use state::*;
 
 // Export the handler functions
 pub use join::handler as join_handler;
 pub use leave::handler as leave_handler;
+pub use straddle::handler as straddle_handler;
+pub use sit_out::{sit_out as sit_out_handler, sit_in as sit_in_handler};
+pub use top_up::{handler as top_up_handler, set_auto_rebuy as auto_rebuy_handler};
+pub use cash_out::handler as cash_out_handler;
+pub use session::{register as register_session_handler, revoke as revoke_session_handler};
+pub use bankroll::{
+    open as open_bankroll_handler,
+    deposit as deposit_bankroll_handler,
+    withdraw as withdraw_bankroll_handler,
+};
+pub use rewards::{
+    open as open_rewards_handler,
+    accrue as accrue_rakeback_handler,
+    claim as claim_rewards_handler,
+};
 
 // Note: JoinGame and LeaveGame structs are now in lib.rs at crate root
\ No newline at end of file
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use crate::advanced::calculate_rewards;
+use crate::game::state::Game;
+use crate::shared::PokerError;
+use crate::treasury::TreasuryLedger;
+use crate::treasury::withdraw::{ledger_route, release_funds};
+use super::state::{PlayerRewards, PlayerState};
+
+/// Open a rewards account for tables in `mint` (default pubkey for SOL)
+/// 
+/// Players are usually tagged on their first join instead; this lets
+/// affiliates (or players joining without a referrer) open one directly.
+pub fn open(ctx: Context<crate::OpenRewards>, mint: Pubkey) -> Result<()> {
+    let referrer = ctx.accounts.referrer.as_ref().map_or(Pubkey::default(), |referrer| referrer.key());
+    
+    ctx.accounts.player_rewards.initialize(
+        ctx.accounts.owner.key(),
+        mint,
+        referrer,
+        ctx.bumps.player_rewards,
+    )?;
+    
+    msg!("[REWARDS] Opened rewards for {} (referrer {})", ctx.accounts.owner.key(), referrer);
+    
+    Ok(())
+}
+
+/// Accrue a player's pending rake into rakeback and affiliate balances
+/// 
+/// Anyone can crank this. Shares come from the table's rake policy and are
+/// reserved on the treasury ledger that pays the claims, so the table's rake
+/// must have been swept there first. Leaving the table or closing it accrues
+/// whatever is still pending (see `accrue_before_close`).
+pub fn accrue(ctx: Context<crate::AccrueRakeback>) -> Result<()> {
+    require!(ctx.accounts.player_state.pending_rake > 0, PokerError::InvalidBetAmount);
+    
+    accrue_pending_rake(
+        &ctx.accounts.game,
+        &mut ctx.accounts.player_state,
+        &mut ctx.accounts.player_rewards,
+        ctx.accounts.referrer_rewards.as_deref_mut(),
+        Some(&mut ctx.accounts.treasury_ledger),
+    )
+}
+
+/// Move pending rake into the player's rakeback and the referrer's affiliate
+/// balance, reserving both on the treasury ledger
+pub(crate) fn accrue_pending_rake(
+    game: &Game,
+    player_state: &mut PlayerState,
+    rewards: &mut PlayerRewards,
+    referrer_rewards: Option<&mut PlayerRewards>,
+    ledger: Option<&mut TreasuryLedger>,
+) -> Result<()> {
+    let rake = player_state.pending_rake;
+    let (rakeback, affiliate) = calculate_rewards(rake, &game.rake, rewards.has_referrer());
+    
+    if affiliate > 0 {
+        let referrer_rewards = referrer_rewards.ok_or(PokerError::InvalidReferrer)?;
+        require_keys_eq!(referrer_rewards.owner, rewards.referrer, PokerError::InvalidReferrer);
+        require_keys_eq!(referrer_rewards.mint, rewards.mint, PokerError::InvalidReferrer);
+        
+        referrer_rewards.affiliate_balance += affiliate;
+    }
+    
+    if rakeback + affiliate > 0 {
+        let ledger = ledger.ok_or(PokerError::RakeNotAccrued)?;
+        require_keys_eq!(ledger.mint, rewards.mint, PokerError::RakeNotAccrued);
+        ledger.reserve(rakeback + affiliate)?;
+    }
+    
+    rewards.total_rake_paid += rake;
+    rewards.rakeback_balance += rakeback;
+    player_state.pending_rake = 0;
+    
+    msg!(
+        "[REWARDS] Player {} accrued {} rake: {} rakeback, {} affiliate",
+        rewards.owner,
+        rake,
+        rakeback,
+        affiliate
+    );
+    
+    Ok(())
+}
+
+/// Accrue pending rake before a PlayerState is closed
+/// 
+/// `rewards_info` is the player's rewards PDA for the table's mint; a player
+/// who never opened one forfeits the rake. The referrer's rewards account is
+/// taken from `referrer_accounts` only when an affiliate share is due.
+/// Nothing is needed when the table's policy pays no rewards.
+pub(crate) fn accrue_before_close<'a, 'info: 'a>(
+    game: &Game,
+    player_state: &mut PlayerState,
+    rewards_info: Option<&'a AccountInfo<'info>>,
+    mut referrer_accounts: impl Iterator<Item = &'a AccountInfo<'info>>,
+    ledger: Option<&mut TreasuryLedger>,
+) -> Result<()> {
+    if player_state.pending_rake == 0 || calculate_rewards(player_state.pending_rake, &game.rake, true) == (0, 0) {
+        player_state.pending_rake = 0;
+        return Ok(());
+    }
+    
+    let rewards_info = rewards_info.ok_or(PokerError::RakeNotAccrued)?;
+    let (rewards_key, _) = Pubkey::find_program_address(
+        &[b"rewards", player_state.player.as_ref(), game.token_mint.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(rewards_info.key(), rewards_key, PokerError::RakeNotAccrued);
+    
+    if rewards_info.owner != &crate::ID {
+        msg!(
+            "[REWARDS] Player {} has no rewards account, {} rake not accrued",
+            player_state.player,
+            player_state.pending_rake
+        );
+        player_state.pending_rake = 0;
+        return Ok(());
+    }
+    
+    let mut rewards = load_rewards(rewards_info)?;
+    let (_, affiliate) = calculate_rewards(player_state.pending_rake, &game.rake, rewards.has_referrer());
+    let referrer_info = if affiliate > 0 { referrer_accounts.next() } else { None };
+    let mut referrer_rewards = referrer_info.map(load_rewards).transpose()?;
+    
+    accrue_pending_rake(game, player_state, &mut rewards, referrer_rewards.as_mut(), ledger)?;
+    
+    store_rewards(&rewards, rewards_info)?;
+    if let (Some(referrer_rewards), Some(referrer_info)) = (referrer_rewards, referrer_info) {
+        store_rewards(&referrer_rewards, referrer_info)?;
+    }
+    
+    Ok(())
+}
+
+fn load_rewards(account_info: &AccountInfo) -> Result<PlayerRewards> {
+    require!(account_info.owner == &crate::ID, PokerError::InvalidReferrer);
+    
+    // try_deserialize checks the discriminator
+    let data = account_info.try_borrow_data()?;
+    PlayerRewards::try_deserialize(&mut &data[..])
+}
+
+fn store_rewards(rewards: &PlayerRewards, account_info: &AccountInfo) -> Result<()> {
+    let mut data = account_info.try_borrow_mut_data()?;
+    let mut writer = &mut data[..];
+    rewards.try_serialize(&mut writer)?;
+    Ok(())
+}
+
+/// Claim rakeback and affiliate balances from the house treasury
+pub fn claim(ctx: Context<crate::ClaimRewards>) -> Result<()> {
+    let rewards = &mut ctx.accounts.player_rewards;
+    let amount = rewards.rakeback_balance + rewards.affiliate_balance;
+    
+    require!(amount > 0, PokerError::InvalidBetAmount);
+    
+    ctx.accounts.treasury_ledger.record_claim(amount)?;
+    
+    let route = ledger_route(
+        &ctx.accounts.treasury_ledger,
+        &ctx.accounts.owner.key(),
+        ctx.accounts.owner_token_account.as_ref(),
+        ctx.accounts.treasury_vault.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    release_funds(
+        &ctx.accounts.treasury,
+        &ctx.accounts.owner.to_account_info(),
+        route,
+        amount,
+    )?;
+    
+    rewards.rakeback_balance = 0;
+    rewards.affiliate_balance = 0;
+    rewards.total_claimed += amount;
+    
+    msg!("[REWARDS] {} claimed {}", rewards.owner, amount);
+    
+    Ok(())
+}
+
+// Uses the OpenRewards, AccrueRakeback and ClaimRewards accounts structs in lib.rs
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::test_game;
+    use crate::advanced::RakeConfig;
+    
+    #[test]
+    fn test_accrued_rewards_reserved_on_ledger() {
+        let mut game = test_game();
+        game.rake = RakeConfig {
+            rakeback_percentage: 2_000, // 20%
+            affiliate_percentage: 1_000, // 10%
+            ..Default::default()
+        };
+        let mut player_state = PlayerState { pending_rake: 500, ..Default::default() };
+        let mut referrer_rewards = PlayerRewards { owner: Pubkey::new_unique(), ..Default::default() };
+        let mut rewards = PlayerRewards {
+            owner: Pubkey::new_unique(),
+            referrer: referrer_rewards.owner,
+            ..Default::default()
+        };
+        let mut ledger = TreasuryLedger { total_collected: 1_000, ..Default::default() };
+        
+        // The referrer's account is needed for the affiliate share
+        assert!(accrue_pending_rake(&game, &mut player_state, &mut rewards, None, Some(&mut ledger)).is_err());
+        
+        accrue_pending_rake(
+            &game,
+            &mut player_state,
+            &mut rewards,
+            Some(&mut referrer_rewards),
+            Some(&mut ledger),
+        ).unwrap();
+        assert_eq!(player_state.pending_rake, 0);
+        assert_eq!(rewards.rakeback_balance, 100);
+        assert_eq!(referrer_rewards.affiliate_balance, 50);
+        
+        // The house can no longer withdraw what players are owed
+        assert_eq!(ledger.reserved, 150);
+        assert_eq!(ledger.balance(), 850);
+        assert!(ledger.record_withdrawal(851).is_err());
+        
+        // Claims draw down the reserve, not the house balance
+        ledger.record_claim(150).unwrap();
+        assert_eq!(ledger.reserved, 0);
+        assert_eq!(ledger.balance(), 850);
+        assert!(ledger.record_claim(1).is_err());
+    }
+}
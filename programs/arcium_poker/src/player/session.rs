@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::shared::{PokerError, constants::MAX_SESSION_DURATION};
+
+/// Register an ephemeral session key that can sign in-hand actions
+/// 
+/// The key is accepted by `PlayerAction` instructions only (betting, timeouts,
+/// sit out/in, straddle, run-it-twice). Leaving, top-ups and withdrawals
+/// always need the wallet owner. Replaces any existing session key.
+pub fn register(ctx: Context<crate::ManageSession>, session_key: Pubkey, expires_at: i64) -> Result<()> {
+    let player_state = &mut ctx.accounts.player_state;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        session_key != Pubkey::default() && session_key != player_state.player,
+        PokerError::InvalidSessionKey
+    );
+    require!(
+        expires_at > now && expires_at <= now + MAX_SESSION_DURATION,
+        PokerError::InvalidSessionKey
+    );
+    
+    player_state.session_key = session_key;
+    player_state.session_expires_at = expires_at;
+    
+    msg!(
+        "[SESSION] Player {} registered session key {} until {}",
+        player_state.player,
+        session_key,
+        expires_at
+    );
+    
+    Ok(())
+}
+
+/// Revoke the player's session key
+pub fn revoke(ctx: Context<crate::ManageSession>) -> Result<()> {
+    let player_state = &mut ctx.accounts.player_state;
+    
+    player_state.session_key = Pubkey::default();
+    player_state.session_expires_at = 0;
+    
+    msg!("[SESSION] Player {} revoked session key", player_state.player);
+    
+    Ok(())
+}
+
+// Uses the ManageSession accounts struct in lib.rs
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::types::PlayerStatus;
+use crate::shared::PokerError;
+
+/// Sit out from the next hand
+/// A player already dealt in plays out the current hand. Blinds that pass
+/// the seat while sitting out are tracked as missed.
+pub fn sit_out(ctx: Context<crate::PlayerAction>) -> Result<()> {
+    let player_state = &mut ctx.accounts.player_state;
+    
+    require!(
+        !matches!(player_state.status, PlayerStatus::SittingOut | PlayerStatus::Left),
+        PokerError::InvalidAction
+    );
+    
+    player_state.status = PlayerStatus::SittingOut;
+    
+    msg!(
+        "[SIT OUT] Player {} is sitting out at seat {}",
+        player_state.player,
+        player_state.seat_index
+    );
+    
+    Ok(())
+}
+
+/// Return from sitting out, dealt in from the next hand
+/// Missed blinds are either posted on returning or the player waits until
+/// the big blind reaches their seat.
+pub fn sit_in(ctx: Context<crate::PlayerAction>, post_missed_blinds: bool) -> Result<()> {
+    let player_state = &mut ctx.accounts.player_state;
+    
+    require!(player_state.is_sitting_out(), PokerError::InvalidAction);
+    require!(player_state.chip_stack > 0, PokerError::InsufficientChips);
+    
+    player_state.status = PlayerStatus::Waiting;
+    player_state.consecutive_timeouts = 0;
+    player_state.waiting_for_big_blind = player_state.has_missed_blinds() && !post_missed_blinds;
+    
+    msg!(
+        "[SIT IN] Player {} returns at seat {} (missed small: {}, missed big: {}, waiting for big blind: {})",
+        player_state.player,
+        player_state.seat_index,
+        player_state.missed_small_blind,
+        player_state.missed_big_blind,
+        player_state.waiting_for_big_blind
+    );
+    
+    Ok(())
+}
+
+// Uses the PlayerAction accounts struct in lib.rs
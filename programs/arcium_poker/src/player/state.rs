@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use crate::types::PlayerStatus;
-use crate::shared::constants::HOLE_CARDS;
+use crate::shared::constants::MAX_HOLE_CARDS;
+use crate::game::state::Game;
 
 /// Player state account (PDA per player per game)
 #[account]
+#[derive(Default)]
 pub struct PlayerState {
     /// Player's public key
     pub player: Pubkey,
@@ -26,8 +28,8 @@ pub struct PlayerState {
     /// Total contribution to pot this hand
     pub total_bet_this_hand: u64,
     
-    /// Encrypted hole cards (indices in deck)
-    pub encrypted_hole_cards: [u8; HOLE_CARDS],
+    /// Encrypted hole cards (indices in deck, 2 used for Hold'em, 4 for Omaha)
+    pub encrypted_hole_cards: [u8; MAX_HOLE_CARDS],
     
     /// Has cards been dealt to this player
     pub has_cards: bool,
@@ -44,6 +46,45 @@ pub struct PlayerState {
     /// Last action timestamp
     pub last_action_at: i64,
     
+    /// Player straddles when in the table's straddle position
+    pub straddle_opt_in: bool,
+    
+    /// Seconds of time bank left once the turn clock runs out
+    pub time_bank: i64,
+    
+    /// Timeouts in a row without acting
+    pub consecutive_timeouts: u8,
+    
+    /// Small blind passed this seat while sitting out
+    pub missed_small_blind: bool,
+    
+    /// Big blind passed this seat while sitting out
+    pub missed_big_blind: bool,
+    
+    /// Returning player waits for the big blind instead of posting missed blinds
+    pub waiting_for_big_blind: bool,
+    
+    /// Chips added automatically when busted (0 = auto-rebuy off)
+    pub auto_rebuy_amount: u64,
+    
+    /// Chips held in escrow to fund auto-rebuys
+    pub rebuy_reserve: u64,
+    
+    /// Ephemeral key allowed to sign in-hand actions for the player
+    pub session_key: Pubkey,
+    
+    /// Unix timestamp after which the session key is no longer accepted
+    pub session_expires_at: i64,
+    
+    /// Chips came from the player's bankroll; top-ups debit it and leaving credits it
+    pub funded_by_bankroll: bool,
+    
+    /// Rake attributed to the player's hands, not yet accrued to rewards
+    pub pending_rake: u64,
+    
+    /// Antes and dead blinds posted this hand (part of `total_bet_this_hand`)
+    pub ante_this_hand: u64,
+    
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -58,14 +99,54 @@ impl PlayerState {
         8 + // chip_stack
         8 + // current_bet
         8 + // total_bet_this_hand
-        (1 * HOLE_CARDS) + // encrypted_hole_cards
+        (1 * MAX_HOLE_CARDS) + // encrypted_hole_cards
         1 + // has_cards
         1 + // has_folded
         1 + // is_all_in
         8 + // joined_at
         8 + // last_action_at
+        1 + // straddle_opt_in
+        8 + // time_bank
+        1 + // consecutive_timeouts
+        1 + // missed_small_blind
+        1 + // missed_big_blind
+        1 + // waiting_for_big_blind
+        8 + // auto_rebuy_amount
+        8 + // rebuy_reserve
+        32 + // session_key
+        8 + // session_expires_at
+        1 + // funded_by_bankroll
+        8 + // pending_rake
+        8 + // ante_this_hand
         1; // bump
     
+    /// Load a player state passed via remaining_accounts, checking owner and game
+    pub fn load(account_info: &AccountInfo, game: &Pubkey) -> Result<Self> {
+        require!(
+            account_info.owner == &crate::ID,
+            crate::shared::PokerError::PlayerNotInGame
+        );
+        
+        // try_deserialize checks the discriminator
+        let data = account_info.try_borrow_data()?;
+        let player_state = Self::try_deserialize(&mut &data[..])?;
+        
+        require!(
+            player_state.game == *game,
+            crate::shared::PokerError::PlayerNotInGame
+        );
+        
+        Ok(player_state)
+    }
+    
+    /// Write a player state back to its account (includes discriminator)
+    pub fn store(&self, account_info: &AccountInfo) -> Result<()> {
+        let mut data = account_info.try_borrow_mut_data()?;
+        let mut writer = &mut data[..];
+        self.try_serialize(&mut writer)?;
+        Ok(())
+    }
+    
     /// Initialize player state
     pub fn initialize(
         &mut self,
@@ -82,12 +163,24 @@ impl PlayerState {
         self.chip_stack = buy_in;
         self.current_bet = 0;
         self.total_bet_this_hand = 0;
-        self.encrypted_hole_cards = [0; HOLE_CARDS];
+        self.ante_this_hand = 0;
+        self.encrypted_hole_cards = [0; MAX_HOLE_CARDS];
         self.has_cards = false;
         self.has_folded = false;
         self.is_all_in = false;
         self.joined_at = Clock::get().unwrap().unix_timestamp;
         self.last_action_at = Clock::get().unwrap().unix_timestamp;
+        self.straddle_opt_in = false;
+        self.consecutive_timeouts = 0;
+        self.missed_small_blind = false;
+        self.missed_big_blind = false;
+        self.waiting_for_big_blind = false;
+        self.auto_rebuy_amount = 0;
+        self.rebuy_reserve = 0;
+        self.session_key = Pubkey::default();
+        self.session_expires_at = 0;
+        self.funded_by_bankroll = false;
+        self.pending_rake = 0;
         self.bump = bump;
     }
     
@@ -112,10 +205,46 @@ impl PlayerState {
         Ok(())
     }
     
+    /// Post an ante as dead money (does not count toward the current bet)
+    /// Returns the amount posted, which is capped at the player's stack
+    pub fn post_ante(&mut self, amount: u64) -> u64 {
+        let posted = amount.min(self.chip_stack);
+        
+        self.chip_stack -= posted;
+        self.total_bet_this_hand += posted;
+        self.ante_this_hand += posted;
+        
+        // Check if all-in
+        if self.chip_stack == 0 {
+            self.is_all_in = true;
+        }
+        
+        posted
+    }
+    
+    /// Post a blind as a live bet
+    /// Returns the amount posted, which is capped at the player's stack
+    pub fn post_blind(&mut self, amount: u64) -> u64 {
+        let posted = amount.min(self.chip_stack);
+        
+        self.chip_stack -= posted;
+        self.current_bet += posted;
+        self.total_bet_this_hand += posted;
+        
+        // Check if all-in
+        if self.chip_stack == 0 {
+            self.is_all_in = true;
+        }
+        
+        posted
+    }
+    
     /// Fold hand
     pub fn fold(&mut self) {
         self.has_folded = true;
-        self.status = PlayerStatus::Folded;
+        if !self.is_sitting_out() && self.status != PlayerStatus::Left {
+            self.status = PlayerStatus::Folded;
+        }
         self.last_action_at = Clock::get().unwrap().unix_timestamp;
     }
     
@@ -128,18 +257,435 @@ impl PlayerState {
     pub fn reset_for_new_hand(&mut self) {
         self.current_bet = 0;
         self.total_bet_this_hand = 0;
-        self.encrypted_hole_cards = [0; HOLE_CARDS];
+        self.ante_this_hand = 0;
+        self.encrypted_hole_cards = [0; MAX_HOLE_CARDS];
         self.has_cards = false;
         self.has_folded = false;
         self.is_all_in = false;
         
-        if self.chip_stack > 0 {
+        if self.chip_stack > 0 && !self.is_sitting_out() {
             self.status = PlayerStatus::Active;
         }
     }
     
+    /// Player acted on their turn, ending any timeout streak
+    /// Time spent past the table's turn clock is drawn from the time bank
+    pub fn record_action(&mut self, game: &Game) {
+        self.record_action_at(game, Clock::get().unwrap().unix_timestamp);
+    }
+    
+    /// Player acted on their turn at a given time
+    pub fn record_action_at(&mut self, game: &Game, now: i64) {
+        let elapsed = now - game.last_action_at;
+        let overtime = (elapsed - game.turn_timeout).max(0);
+        
+        self.time_bank = (self.time_bank - overtime).max(0);
+        self.consecutive_timeouts = 0;
+    }
+    
+    /// Add time back to the time bank at the start of a hand
+    pub fn refill_time_bank(&mut self, game: &Game) {
+        self.time_bank = (self.time_bank + game.time_bank_refill).min(game.time_bank_max);
+    }
+    
+    /// Check if player is sitting out
+    pub fn is_sitting_out(&self) -> bool {
+        self.status == PlayerStatus::SittingOut
+    }
+    
+    /// Check if player owes blinds missed while sitting out
+    pub fn has_missed_blinds(&self) -> bool {
+        self.missed_small_blind || self.missed_big_blind
+    }
+    
+    /// Clear missed blinds once they are posted or covered by the big blind
+    pub fn clear_missed_blinds(&mut self) {
+        self.missed_small_blind = false;
+        self.missed_big_blind = false;
+        self.waiting_for_big_blind = false;
+    }
+    
+    /// Add chips to the stack, capped so it never exceeds the max buy-in
+    /// Returns the chips added
+    pub fn top_up(&mut self, amount: u64, max_buy_in: u64) -> u64 {
+        let added = amount.min(max_buy_in.saturating_sub(self.chip_stack));
+        self.chip_stack += added;
+        added
+    }
+    
+    /// Rebuy from the reserve when busted and auto-rebuy is on
+    /// Returns the chips added
+    pub fn try_auto_rebuy(&mut self, max_buy_in: u64) -> u64 {
+        if self.chip_stack > 0
+            || self.auto_rebuy_amount == 0
+            || self.rebuy_reserve < self.auto_rebuy_amount
+        {
+            return 0;
+        }
+        
+        let added = self.top_up(self.auto_rebuy_amount, max_buy_in);
+        self.rebuy_reserve -= added;
+        added
+    }
+    
+    /// Check if a signer may act for the player in game
+    /// Accepts the wallet owner or an unexpired session key
+    pub fn is_authorized(&self, signer: &Pubkey) -> bool {
+        Clock::get().is_ok_and(|clock| self.is_authorized_at(signer, clock.unix_timestamp))
+    }
+    
+    /// Check if a signer may act for the player in game at a given time
+    pub fn is_authorized_at(&self, signer: &Pubkey, now: i64) -> bool {
+        if *signer == self.player {
+            return true;
+        }
+        
+        self.session_key != Pubkey::default()
+            && *signer == self.session_key
+            && now < self.session_expires_at
+    }
+    
     /// Add winnings
     pub fn add_winnings(&mut self, amount: u64) {
         self.chip_stack += amount;
     }
-}
\ No newline at end of file
+}
+
+/// Player bankroll shared across tables (PDA per player wallet)
+/// Holds lamports for SOL tables so players can buy in and cash out
+/// without a wallet transfer at every table
+#[account]
+#[derive(Default)]
+pub struct Bankroll {
+    /// Wallet that owns the bankroll
+    pub owner: Pubkey,
+    
+    /// Chips available to buy in with (lamports above rent)
+    pub balance: u64,
+    
+    /// Tables the owner is seated at with chips from this bankroll
+    pub seated_tables: u8,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Bankroll {
+    /// Calculate space needed for Bankroll account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 + // balance
+        1 + // seated_tables
+        1; // bump
+    
+    /// Take chips out of the bankroll balance
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        require!(
+            self.balance >= amount,
+            crate::shared::PokerError::InsufficientBalance
+        );
+        self.balance -= amount;
+        Ok(())
+    }
+    
+    /// Return chips to the bankroll balance
+    pub fn credit(&mut self, amount: u64) {
+        self.balance += amount;
+    }
+}
+
+/// Rakeback and affiliate rewards for a player (PDA per wallet and table mint)
+/// Balances are in the mint's smallest unit (lamports for SOL tables) and
+/// are paid out of the house treasury
+#[account]
+#[derive(Default)]
+pub struct PlayerRewards {
+    /// Wallet that earns and claims the rewards
+    pub owner: Pubkey,
+    
+    /// Table mint the rewards are denominated in (default for SOL)
+    pub mint: Pubkey,
+    
+    /// Wallet that referred the owner (default if none)
+    pub referrer: Pubkey,
+    
+    /// Rake the owner has paid, as accrued so far
+    pub total_rake_paid: u64,
+    
+    /// Claimable rakeback on the owner's own rake
+    pub rakeback_balance: u64,
+    
+    /// Claimable share of rake paid by players the owner referred
+    pub affiliate_balance: u64,
+    
+    /// Total rewards claimed
+    pub total_claimed: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PlayerRewards {
+    /// Calculate space needed for PlayerRewards account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // mint
+        32 + // referrer
+        8 + // total_rake_paid
+        8 + // rakeback_balance
+        8 + // affiliate_balance
+        8 + // total_claimed
+        1; // bump
+    
+    /// Initialize rewards for a wallet, tagging its referrer
+    pub fn initialize(&mut self, owner: Pubkey, mint: Pubkey, referrer: Pubkey, bump: u8) -> Result<()> {
+        require!(referrer != owner, crate::shared::PokerError::InvalidReferrer);
+        
+        self.owner = owner;
+        self.mint = mint;
+        self.referrer = referrer;
+        self.total_rake_paid = 0;
+        self.rakeback_balance = 0;
+        self.affiliate_balance = 0;
+        self.total_claimed = 0;
+        self.bump = bump;
+        Ok(())
+    }
+    
+    /// Check if the owner was referred
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
+}
+
+/// PlayerState accounts for every occupied seat, indexed by seat
+/// Empty seats hold a default (zero-chip) state and no account
+pub struct SeatedPlayers<'a, 'info> {
+    pub states: Vec<PlayerState>,
+    pub accounts: Vec<Option<&'a AccountInfo<'info>>>,
+}
+
+impl<'a, 'info> SeatedPlayers<'a, 'info> {
+    /// Load PlayerState accounts passed via remaining_accounts (in any order)
+    /// Every occupied seat must be covered exactly once
+    pub fn load(
+        player_accounts: &'a [AccountInfo<'info>],
+        game: &Game,
+        game_key: &Pubkey,
+    ) -> Result<Self> {
+        Self::load_seats(player_accounts, game, game_key, None)
+    }
+    
+    /// Load the other seated players around one the instruction already holds
+    /// `player_state` fills its own seat as-is (its account data may be stale
+    /// or not yet written); `player_accounts` cover every other occupied seat
+    pub fn load_around(
+        player_accounts: &'a [AccountInfo<'info>],
+        game: &Game,
+        game_key: &Pubkey,
+        player_state: &PlayerState,
+    ) -> Result<Self> {
+        Self::load_seats(player_accounts, game, game_key, Some(player_state))
+    }
+    
+    fn load_seats(
+        player_accounts: &'a [AccountInfo<'info>],
+        game: &Game,
+        game_key: &Pubkey,
+        held: Option<&PlayerState>,
+    ) -> Result<Self> {
+        let seat_count = game.seat_count() as usize;
+        let mut states = vec![PlayerState::default(); seat_count];
+        let mut accounts: Vec<Option<&'a AccountInfo<'info>>> = vec![None; seat_count];
+        let mut covered = vec![false; seat_count];
+        
+        if let Some(held) = held {
+            let seat = held.seat_index as usize;
+            require!(seat < seat_count, crate::shared::PokerError::InvalidSeatPosition);
+            states[seat] = held.clone();
+            covered[seat] = true;
+        }
+        
+        for account_info in player_accounts {
+            let player_state = PlayerState::load(account_info, game_key)?;
+            let seat = player_state.seat_index as usize;
+            
+            require!(
+                seat < seat_count && !covered[seat],
+                crate::shared::PokerError::InvalidSeatPosition
+            );
+            require!(
+                game.players[seat] == player_state.player,
+                crate::shared::PokerError::PlayerNotInGame
+            );
+            
+            states[seat] = player_state;
+            accounts[seat] = Some(account_info);
+            covered[seat] = true;
+        }
+        
+        for seat in 0..game.seat_count() {
+            require!(
+                !game.is_seat_occupied(seat) || covered[seat as usize],
+                crate::shared::PokerError::PlayerNotInGame
+            );
+        }
+        
+        Ok(Self { states, accounts })
+    }
+    
+    /// Write every loaded state back to its account
+    /// A state passed to `load_around` has no account here and is not written
+    pub fn store_all(&self) -> Result<()> {
+        for (player_state, account_info) in self.states.iter().zip(&self.accounts) {
+            if let Some(account_info) = account_info {
+                player_state.store(account_info)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::test_game;
+    
+    #[test]
+    fn test_held_state_fills_its_seat() {
+        let mut game = test_game();
+        let player = Pubkey::new_unique();
+        game.add_player_at_seat(player, 2).unwrap();
+        let player_state = PlayerState { player, seat_index: 2, chip_stack: 300, ..Default::default() };
+        let game_key = Pubkey::new_unique();
+        
+        let seated = SeatedPlayers::load_around(&[], &game, &game_key, &player_state).unwrap();
+        assert_eq!(seated.states[2].chip_stack, 300);
+        assert!(seated.accounts[2].is_none());
+        
+        // Every other occupied seat still needs its account
+        game.add_player_at_seat(Pubkey::new_unique(), 4).unwrap();
+        assert!(SeatedPlayers::load_around(&[], &game, &game_key, &player_state).is_err());
+    }
+    
+    #[test]
+    fn test_top_up_capped_at_max_buy_in() {
+        let mut player_state = PlayerState { chip_stack: 700, ..Default::default() };
+        
+        assert_eq!(player_state.top_up(500, 1_000), 300);
+        assert_eq!(player_state.chip_stack, 1_000);
+        
+        // Already at the max, nothing is added
+        assert_eq!(player_state.top_up(100, 1_000), 0);
+        assert_eq!(player_state.chip_stack, 1_000);
+    }
+    
+    #[test]
+    fn test_auto_rebuy_only_when_busted() {
+        let mut player_state = PlayerState {
+            chip_stack: 50,
+            auto_rebuy_amount: 400,
+            rebuy_reserve: 1_000,
+            ..Default::default()
+        };
+        
+        // Still has chips
+        assert_eq!(player_state.try_auto_rebuy(1_000), 0);
+        
+        player_state.chip_stack = 0;
+        assert_eq!(player_state.try_auto_rebuy(1_000), 400);
+        assert_eq!(player_state.chip_stack, 400);
+        assert_eq!(player_state.rebuy_reserve, 600);
+        
+        // Turned off
+        player_state.chip_stack = 0;
+        player_state.auto_rebuy_amount = 0;
+        assert_eq!(player_state.try_auto_rebuy(1_000), 0);
+        
+        // Reserve too small for a full rebuy
+        player_state.auto_rebuy_amount = 700;
+        assert_eq!(player_state.try_auto_rebuy(1_000), 0);
+        assert_eq!(player_state.rebuy_reserve, 600);
+    }
+    
+    #[test]
+    fn test_auto_rebuy_respects_max_buy_in() {
+        let mut player_state = PlayerState {
+            auto_rebuy_amount: 500,
+            rebuy_reserve: 500,
+            ..Default::default()
+        };
+        
+        // Only what fits under the cap leaves the reserve
+        assert_eq!(player_state.try_auto_rebuy(300), 300);
+        assert_eq!(player_state.chip_stack, 300);
+        assert_eq!(player_state.rebuy_reserve, 200);
+    }
+    
+    #[test]
+    fn test_time_bank_drains_past_turn_clock_and_refills() {
+        let mut game = test_game();
+        game.turn_timeout = 30;
+        game.time_bank_max = 60;
+        game.time_bank_refill = 10;
+        game.last_action_at = 1_000;
+        let mut player_state = PlayerState {
+            time_bank: 60,
+            consecutive_timeouts: 2,
+            ..Default::default()
+        };
+        
+        // Acting within the turn clock costs nothing
+        player_state.record_action_at(&game, 1_030);
+        assert_eq!(player_state.time_bank, 60);
+        assert_eq!(player_state.consecutive_timeouts, 0);
+        
+        // 45 seconds over the clock come out of the bank
+        player_state.record_action_at(&game, 1_075);
+        assert_eq!(player_state.time_bank, 15);
+        
+        // The bank never goes negative
+        player_state.record_action_at(&game, 1_100);
+        assert_eq!(player_state.time_bank, 0);
+        
+        // Each hand adds the refill, up to the max
+        player_state.refill_time_bank(&game);
+        assert_eq!(player_state.time_bank, 10);
+        player_state.time_bank = 55;
+        player_state.refill_time_bank(&game);
+        assert_eq!(player_state.time_bank, 60);
+    }
+    
+    #[test]
+    fn test_session_key_authorization() {
+        let mut player_state = PlayerState {
+            player: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let session_key = Pubkey::new_unique();
+        
+        // The wallet owner is always accepted
+        assert!(player_state.is_authorized_at(&player_state.player, 0));
+        
+        // No session registered
+        assert!(!player_state.is_authorized_at(&Pubkey::default(), 0));
+        assert!(!player_state.is_authorized_at(&session_key, 0));
+        
+        player_state.session_key = session_key;
+        player_state.session_expires_at = 1_000;
+        assert!(player_state.is_authorized_at(&session_key, 999));
+        
+        // Expired
+        assert!(!player_state.is_authorized_at(&session_key, 1_000));
+        
+        // Another player's session key cannot act for this one
+        let other = PlayerState {
+            player: Pubkey::new_unique(),
+            session_key: Pubkey::new_unique(),
+            session_expires_at: 1_000,
+            ..Default::default()
+        };
+        assert!(!player_state.is_authorized_at(&other.session_key, 500));
+        assert!(!other.is_authorized_at(&session_key, 500));
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::types::StraddleType;
+use crate::shared::PokerError;
+
+/// Opt in or out of straddling when in the table's straddle position
+/// Takes effect from the next hand
+pub fn handler(ctx: Context<crate::PlayerAction>, enabled: bool) -> Result<()> {
+    let game = &ctx.accounts.game;
+    let player_state = &mut ctx.accounts.player_state;
+    
+    require!(
+        !enabled || game.straddle_type != StraddleType::None,
+        PokerError::InvalidAction
+    );
+    
+    player_state.straddle_opt_in = enabled;
+    
+    msg!(
+        "[STRADDLE] Player {} straddle opt-in: {}",
+        player_state.player,
+        enabled
+    );
+    
+    Ok(())
+}
+
+// Uses the PlayerAction accounts struct in lib.rs
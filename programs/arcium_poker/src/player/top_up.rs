@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::types::PlayerStatus;
+use crate::shared::{PokerError, validate_buy_in};
+use crate::token::{TokenRoute, deposit_chips};
+use crate::security::validate_escrow_invariants;
+use super::bankroll::debit_bankroll;
+
+/// Add chips to a player's stack at a cash table
+/// 
+/// Only allowed while the player is not in a hand. The amount is capped so
+/// the resulting stack never exceeds the table's max buy-in.
+pub fn handler(ctx: Context<crate::TopUp>, amount: u64) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let player_state = &mut ctx.accounts.player_state;
+    
+    require!(
+        player_state.status != PlayerStatus::Left,
+        PokerError::PlayerNotInGame
+    );
+    
+    // Table stakes: the stack cannot change while the player is in a hand
+    require!(
+        !game.is_hand_in_progress() || !game.active_players[player_state.seat_index as usize],
+        PokerError::CannotTopUpDuringHand
+    );
+    
+    let added = player_state.top_up(amount, game.max_buy_in);
+    require!(added > 0, PokerError::BuyInTooHigh);
+    
+    // Debit the bankroll that funded the seat, or transfer from the wallet to
+    // game escrow (lamports, or tokens on a token table)
+    let credited = match ctx.accounts.bankroll.as_mut() {
+        Some(bankroll) if player_state.funded_by_bankroll => {
+            debit_bankroll(game, bankroll, added)?;
+            added
+        }
+        None if player_state.funded_by_bankroll => return err!(PokerError::InvalidBankroll),
+        _ => {
+            let route = TokenRoute::for_game(
+                game,
+                ctx.accounts.player_token_account.as_ref(),
+                ctx.accounts.escrow_token_account.as_ref(),
+                ctx.accounts.token_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            )?;
+            deposit_chips(
+                game,
+                &ctx.accounts.player,
+                &ctx.accounts.system_program,
+                route,
+                added,
+            )?
+        }
+    };
+    
+    // Transfer fees can leave less in escrow than was sent
+    player_state.chip_stack -= added - credited;
+    
+    validate_escrow_invariants(
+        game,
+        ctx.remaining_accounts,
+        Some(player_state),
+        ctx.accounts.jackpot.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+    )?;
+    
+    msg!(
+        "Player {} topped up {} chips (stack: {})",
+        player_state.player,
+        credited,
+        player_state.chip_stack
+    );
+    
+    Ok(())
+}
+
+/// Configure automatic rebuy and deposit into the rebuy reserve
+/// 
+/// When the player busts, `rebuy_amount` is moved from the reserve to their
+/// stack before the next hand. A `rebuy_amount` of 0 turns auto-rebuy off.
+pub fn set_auto_rebuy(ctx: Context<crate::TopUp>, rebuy_amount: u64, deposit: u64) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let player_state = &mut ctx.accounts.player_state;
+    
+    require!(
+        player_state.status != PlayerStatus::Left,
+        PokerError::PlayerNotInGame
+    );
+    
+    if rebuy_amount > 0 {
+        validate_buy_in(rebuy_amount, game.min_buy_in, game.max_buy_in)?;
+    }
+    
+    let mut credited = 0;
+    if deposit > 0 {
+        // Fund the reserve like a top-up: from the bankroll that funded the
+        // seat, or from the wallet (lamports, or tokens on a token table)
+        credited = match ctx.accounts.bankroll.as_mut() {
+            Some(bankroll) if player_state.funded_by_bankroll => {
+                debit_bankroll(game, bankroll, deposit)?;
+                deposit
+            }
+            None if player_state.funded_by_bankroll => return err!(PokerError::InvalidBankroll),
+            _ => {
+                let route = TokenRoute::for_game(
+                    game,
+                    ctx.accounts.player_token_account.as_ref(),
+                    ctx.accounts.escrow_token_account.as_ref(),
+                    ctx.accounts.token_mint.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                )?;
+                deposit_chips(
+                    game,
+                    &ctx.accounts.player,
+                    &ctx.accounts.system_program,
+                    route,
+                    deposit,
+                )?
+            }
+        };
+    }
+    
+    player_state.auto_rebuy_amount = rebuy_amount;
+    player_state.rebuy_reserve += credited;
+    
+    validate_escrow_invariants(
+        game,
+        ctx.remaining_accounts,
+        Some(player_state),
+        ctx.accounts.jackpot.as_ref(),
+        ctx.accounts.escrow_token_account.as_ref(),
+    )?;
+    
+    msg!(
+        "Player {} auto-rebuy: {} (reserve: {})",
+        player_state.player,
+        rebuy_amount,
+        player_state.rebuy_reserve
+    );
+    
+    Ok(())
+}
+
+// Uses the TopUp accounts struct in lib.rs
@@ -51,7 +51,7 @@ pub fn audit_game_actions(
     
     let mut suspicious_count = 0;
     
-    for i in 0..game.player_count as usize {
+    for i in 0..game.seat_count() as usize {
         let player = &player_states[i];
         
         // Flag if player always folds (potential bot)
@@ -96,7 +96,7 @@ pub fn check_timeout_stalling(
     let time_since_action = current_time - game.last_action_at;
     
     // Return true if player is stalling (close to timeout)
-    let is_stalling = time_since_action > (crate::shared::constants::TURN_TIMEOUT * 3 / 4);
+    let is_stalling = time_since_action > (game.turn_timeout * 3 / 4);
     
     if is_stalling {
         msg!(
@@ -115,7 +115,7 @@ pub fn verify_game_integrity(
 ) -> Result<()> {
     // Check chip conservation
     let mut total_in_play = game.pot;
-    for i in 0..game.player_count as usize {
+    for i in 0..game.seat_count() as usize {
         total_in_play += player_states[i].chip_stack;
         total_in_play += player_states[i].current_bet;
     }
@@ -126,7 +126,7 @@ pub fn verify_game_integrity(
     );
     
     // Verify active player count
-    let active_count = game.active_players[..game.player_count as usize]
+    let active_count = game.active_players[..game.seat_count() as usize]
         .iter()
         .filter(|&&active| active)
         .count();
@@ -7,6 +7,8 @@ pub mod zkp;
 pub use validation::{
     validate_game_state,
     validate_chip_conservation,
+    validate_escrow_balance,
+    validate_escrow_invariants,
     validate_deck_integrity,
     validate_state_transition,
 };
@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 use crate::game::state::Game;
-use crate::player::state::PlayerState;
+use crate::player::state::{PlayerState, SeatedPlayers};
+use crate::advanced::Jackpot;
 use crate::types::GameStage;
 use crate::shared::{PokerError, constants::*};
+use crate::token::{chips_to_tokens, current_balance};
 
 /// Validate game state invariants
-pub fn validate_game_state(game: &Game, player_states: &[PlayerState]) -> Result<()> {
+pub fn validate_game_state(game: &Game, player_states: &[PlayerState], jackpot_balance: u64) -> Result<()> {
+    // Validate account layout
+    require!(
+        game.version == GAME_ACCOUNT_VERSION,
+        PokerError::InvalidGameConfig
+    );
+    
     // Validate player count
     require!(
         game.player_count <= game.max_players,
@@ -13,52 +22,115 @@ pub fn validate_game_state(game: &Game, player_states: &[PlayerState]) -> Result
     );
     
     require!(
-        game.player_count as usize <= MAX_PLAYERS,
+        game.max_players as usize <= MAX_PLAYERS,
         PokerError::InvalidGameConfig
     );
     
     // Validate dealer position
     require!(
-        game.dealer_position < game.player_count,
+        game.dealer_position < game.seat_count(),
         PokerError::InvalidGameConfig
     );
     
     // Validate current player
     if game.stage != GameStage::Waiting && game.stage != GameStage::Finished {
         require!(
-            game.current_player_index < game.player_count,
+            game.current_player_index < game.seat_count(),
             PokerError::InvalidGameConfig
         );
     }
     
     // Validate chip conservation
-    validate_chip_conservation(game, player_states)?;
+    validate_chip_conservation(game, player_states, jackpot_balance)?;
     
     Ok(())
 }
 
-/// Validate chip conservation (total chips = player stacks + pot)
-pub fn validate_chip_conservation(game: &Game, player_states: &[PlayerState]) -> Result<()> {
+/// Validate chip conservation
+/// Every chip the escrow owes must sit in a stack, a rebuy reserve, the pot,
+/// unswept rake or the jackpot pool. `player_states` must cover every
+/// occupied seat, indexed by seat.
+pub fn validate_chip_conservation(
+    game: &Game,
+    player_states: &[PlayerState],
+    jackpot_balance: u64,
+) -> Result<()> {
     let mut total_player_chips = 0u64;
     
-    for i in 0..game.player_count as usize {
-        if game.active_players[i] {
-            total_player_chips += player_states[i].chip_stack;
-            total_player_chips += player_states[i].total_bet_this_hand;
+    for seat in 0..game.seat_count() {
+        if game.is_seat_occupied(seat) {
+            let player_state = &player_states[seat as usize];
+            total_player_chips += player_state.chip_stack + player_state.rebuy_reserve;
         }
     }
     
-    // Total chips should equal player stacks + pot
-    // Note: This is a simplified check. In production, track initial total
+    let accounted = total_player_chips + game.pot + game.collected_rake + jackpot_balance;
+    
     msg!(
-        "[SECURITY] Chip conservation check: {} in stacks + bets, {} in pot",
+        "[SECURITY] Chip conservation check: {} in stacks, {} in pot, {} rake, {} jackpot ({} escrowed)",
         total_player_chips,
-        game.pot
+        game.pot,
+        game.collected_rake,
+        jackpot_balance,
+        game.escrowed_chips
     );
     
+    require!(accounted == game.escrowed_chips, PokerError::EscrowImbalance);
+    
+    Ok(())
+}
+
+/// Validate that the escrow holds every chip the table owes
+/// The game PDA's lamports above rent (or the escrow token balance on a token
+/// table) must cover `escrowed_chips`. Value sent to the escrow directly is
+/// only surplus, so anyone could freeze a table under strict equality; a
+/// shortfall means value left the escrow without being accounted for.
+pub fn validate_escrow_balance(
+    game: &Account<Game>,
+    escrow_token_account: Option<&InterfaceAccount<TokenAccount>>,
+) -> Result<()> {
+    let (held, owed) = if game.is_token_table() {
+        let escrow = escrow_token_account.ok_or(PokerError::InvalidTokenAccounts)?;
+        require_keys_eq!(escrow.key(), game.token_escrow, PokerError::InvalidTokenAccounts);
+        (
+            current_balance(escrow)?,
+            chips_to_tokens(game.escrowed_chips, &game.conversion_rate),
+        )
+    } else {
+        let game_info = game.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(game_info.data_len());
+        (game_info.lamports().saturating_sub(rent_exempt), game.escrowed_chips)
+    };
+    
+    require!(held >= owed, PokerError::EscrowImbalance);
+    
     Ok(())
 }
 
+/// Validate the full escrow invariant after chips enter or leave the table
+/// `player_accounts` are the PlayerStates of the occupied seats, loaded like
+/// `SeatedPlayers::load`; a state the instruction changed is passed as
+/// `player_state` and left out of them. Every chip escrowed must be
+/// accounted for, and the escrow must hold it.
+pub fn validate_escrow_invariants(
+    game: &Account<Game>,
+    player_accounts: &[AccountInfo],
+    player_state: Option<&PlayerState>,
+    jackpot: Option<&Account<Jackpot>>,
+    escrow_token_account: Option<&InterfaceAccount<TokenAccount>>,
+) -> Result<()> {
+    require!(jackpot.is_some() == game.has_jackpot, PokerError::InvalidJackpot);
+    
+    let game_key = game.key();
+    let seated = match player_state {
+        Some(player_state) => SeatedPlayers::load_around(player_accounts, game, &game_key, player_state)?,
+        None => SeatedPlayers::load(player_accounts, game, &game_key)?,
+    };
+    validate_chip_conservation(game, &seated.states, jackpot.map_or(0, |jackpot| jackpot.balance))?;
+    
+    validate_escrow_balance(game, escrow_token_account)
+}
+
 /// Validate deck integrity (52 unique cards)
 pub fn validate_deck_integrity(encrypted_deck: &[u8; 52]) -> Result<()> {
     // Check for duplicate card indices
@@ -195,9 +267,113 @@ pub fn validate_no_timeout(game: &Game, current_time: i64) -> Result<()> {
     let time_since_action = current_time - game.last_action_at;
     
     require!(
-        time_since_action < TURN_TIMEOUT,
+        time_since_action < game.turn_timeout,
         PokerError::InvalidAction
     );
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_utils::seated_game;
+    use crate::advanced::{rake_pot, feed_jackpot, RakeConfig};
+    use crate::advanced::jackpot::{Jackpot, JackpotConfig};
+    use crate::game::new_hand::award_uncontested_pot;
+    use crate::showdown::payout::distribute_winnings;
+    
+    /// Three players who bought in for 100 each, with `bets` in the pot
+    fn hand_with_bets(bets: [u64; 3]) -> (Game, Vec<PlayerState>) {
+        let (mut game, mut states) = seated_game(&[100, 100, 100]);
+        game.rake = RakeConfig {
+            rake_percentage: 1_000,
+            rake_cap: 100,
+            min_pot_for_rake: 0,
+            no_flop_no_drop: false,
+            ..Default::default()
+        };
+        game.players_dealt = 3;
+        
+        for (seat, &bet) in bets.iter().enumerate() {
+            states[seat].chip_stack -= bet;
+            states[seat].total_bet_this_hand = bet;
+            game.pot += bet;
+        }
+        
+        // A stale account at an empty seat is not counted
+        states[4].chip_stack = 999;
+        
+        (game, states)
+    }
+    
+    #[test]
+    fn test_conservation_through_uncontested_pot() {
+        let (mut game, mut states) = hand_with_bets([10, 20, 20]);
+        validate_chip_conservation(&game, &states, 0).unwrap();
+        
+        // Seats 0 and 2 fold to seat 1
+        for seat in [0, 2] {
+            states[seat].has_folded = true;
+            game.active_players[seat] = false;
+        }
+        game.stage = GameStage::Finished;
+        
+        let mut jackpot = Jackpot {
+            game: Pubkey::new_unique(),
+            config: JackpotConfig { drop_per_pot: 2, ..Default::default() },
+            balance: 0,
+            total_paid: 0,
+            hits: 0,
+            bump: 0,
+        };
+        award_uncontested_pot(&mut game, &mut states, Some(&mut jackpot)).unwrap();
+        
+        // 5 rake, 2 of it to the jackpot; the winner takes the other 45
+        assert_eq!(game.collected_rake, 3);
+        assert_eq!(jackpot.balance, 2);
+        assert_eq!(states[1].chip_stack, 125);
+        validate_chip_conservation(&game, &states, jackpot.balance).unwrap();
+        
+        // Rakeback is only owed on the 3 that reaches the treasury
+        assert_eq!(states.iter().map(|player_state| player_state.pending_rake).sum::<u64>(), 3);
+        
+        // The jackpot pool is part of what the escrow owes
+        assert!(validate_chip_conservation(&game, &states, 0).is_err());
+        
+        // A rebuy reserve is owed too
+        states[2].rebuy_reserve = 50;
+        assert!(validate_chip_conservation(&game, &states, jackpot.balance).is_err());
+        game.escrowed_chips += 50;
+        validate_chip_conservation(&game, &states, jackpot.balance).unwrap();
+    }
+    
+    #[test]
+    fn test_conservation_through_showdown() {
+        let (mut game, mut states) = hand_with_bets([50, 50, 50]);
+        game.stage = GameStage::Showdown;
+        
+        let mut jackpot = Jackpot {
+            game: Pubkey::new_unique(),
+            config: JackpotConfig { drop_per_pot: 5, ..Default::default() },
+            balance: 40,
+            total_paid: 0,
+            hits: 0,
+            bump: 0,
+        };
+        game.escrowed_chips += jackpot.balance;
+        
+        rake_pot(&mut game).unwrap();
+        feed_jackpot(&mut game, &mut jackpot);
+        assert_eq!((game.pot, game.collected_rake, jackpot.balance), (135, 10, 45));
+        
+        // Seat 0 scoops the main pot, seat 2 splits the rest
+        distribute_winnings(&mut game, &mut states, &[(0, 90), (2, 45)]).unwrap();
+        validate_chip_conservation(&game, &states, jackpot.balance).unwrap();
+        
+        // A payout that leaves chips behind is caught
+        let (mut game, mut states) = hand_with_bets([50, 50, 50]);
+        distribute_winnings(&mut game, &mut states, &[(0, 149)]).unwrap();
+        assert!(validate_chip_conservation(&game, &states, 0).is_err());
+    }
+}
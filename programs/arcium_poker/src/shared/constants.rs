@@ -1,5 +1,8 @@
 /// Maximum number of players per game
-pub const MAX_PLAYERS: usize = 6;
+pub const MAX_PLAYERS: usize = 10;
+
+/// Game account layout version, bumped whenever `Game` fields change
+pub const GAME_ACCOUNT_VERSION: u8 = 13;
 
 /// Minimum number of players to start
 pub const MIN_PLAYERS: usize = 2;
@@ -7,18 +10,54 @@ pub const MIN_PLAYERS: usize = 2;
 /// Number of hole cards per player
 pub const HOLE_CARDS: usize = 2;
 
+/// Number of hole cards per player in Omaha
+pub const OMAHA_HOLE_CARDS: usize = 4;
+
+/// Hole card capacity of a player account (largest variant)
+pub const MAX_HOLE_CARDS: usize = OMAHA_HOLE_CARDS;
+
+/// Highest card allowed in a qualifying low hand (8-or-better)
+pub const LOW_HAND_QUALIFIER: u8 = 8;
+
 /// Number of community cards
 pub const COMMUNITY_CARDS: usize = 5;
 
 /// Total cards in deck
 pub const DECK_SIZE: usize = 52;
 
-/// Turn timeout in seconds
+/// Total cards in a short deck (2s through 5s removed)
+pub const SHORT_DECK_SIZE: usize = 36;
+
+/// Lowest rank in a short deck
+pub const SHORT_DECK_LOWEST_RANK: u8 = 6;
+
+/// Marker for unused deck slots when playing a reduced deck
+pub const EMPTY_CARD_SLOT: u8 = u8::MAX;
+
+/// Default turn timeout in seconds
 pub const TURN_TIMEOUT: i64 = 60;
 
+/// Default time bank in seconds, used once the turn clock runs out
+pub const DEFAULT_TIME_BANK: i64 = 60;
+
+/// Default seconds added back to each time bank per hand
+pub const DEFAULT_TIME_BANK_REFILL: i64 = 5;
+
+/// Longest a session key can stay valid, in seconds
+pub const MAX_SESSION_DURATION: i64 = 24 * 60 * 60;
+
+/// Most members in the treasury's withdrawal signer set
+pub const MAX_TREASURY_SIGNERS: usize = 5;
+
+/// Consecutive timeouts before a player is sat out automatically
+pub const MAX_CONSECUTIVE_TIMEOUTS: u8 = 2;
+
 /// Minimum raise multiplier
 pub const MIN_RAISE_MULTIPLIER: u64 = 2;
 
+/// Straddle size as a multiple of the big blind
+pub const STRADDLE_MULTIPLIER: u64 = 2;
+
 /// Default small blind amount (in lamports/smallest unit)
 pub const DEFAULT_SMALL_BLIND: u64 = 1_000_000; // 0.001 SOL or equivalent
 
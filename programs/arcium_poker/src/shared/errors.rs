@@ -70,4 +70,58 @@ pub enum PokerError {
     
     #[msg("Game has not finished")]
     GameNotFinished,
-}
\ No newline at end of file
+    
+    #[msg("Cannot add chips during active hand")]
+    CannotTopUpDuringHand,
+    
+    #[msg("Action was built for a different hand or action sequence")]
+    StaleAction,
+    
+    #[msg("Signer is not the player or an active session key")]
+    UnauthorizedSigner,
+    
+    #[msg("Invalid session key or expiry")]
+    InvalidSessionKey,
+    
+    #[msg("Token accounts do not match the table's mint or escrow")]
+    InvalidTokenAccounts,
+    
+    #[msg("Token mint has extensions or authorities the table does not allow")]
+    UnsupportedMint,
+    
+    #[msg("Bankroll is missing or cannot fund this table")]
+    InvalidBankroll,
+    
+    #[msg("Cannot withdraw from bankroll while seated at a table")]
+    BankrollInUse,
+    
+    #[msg("Invalid treasury signer set or threshold")]
+    InvalidTreasuryConfig,
+    
+    #[msg("Treasury withdrawal needs the admin or enough approving signers")]
+    TreasuryNotApproved,
+    
+    #[msg("Referrer is invalid or their rewards account is missing")]
+    InvalidReferrer,
+    
+    #[msg("Invalid jackpot configuration")]
+    InvalidJackpotConfig,
+    
+    #[msg("Jackpot account is missing or does not belong to this game")]
+    InvalidJackpot,
+    
+    #[msg("Table still holds chips, rake or a jackpot and cannot be closed")]
+    TableNotEmpty,
+    
+    #[msg("Escrow balance does not cover the chips owed by the table")]
+    EscrowImbalance,
+    
+    #[msg("Chips cannot be taken off the table while ratholing protection is on")]
+    RatholingNotAllowed,
+    
+    #[msg("Pending rake must be accrued to the player's rewards before the seat is closed")]
+    RakeNotAccrued,
+    
+    #[msg("Game account layout is outdated, migrate it with migrate_game")]
+    GameAccountOutdated,
+}
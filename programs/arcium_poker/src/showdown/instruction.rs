@@ -4,10 +4,16 @@ use crate::player::state::PlayerState;
 use crate::cards::deck::Card;
 use crate::arcium::mpc_reveal::{mpc_reveal_card, RevealParams};
 use crate::betting::pot_manager::PotManager;
-use crate::types::GameStage;
+use crate::types::{GameStage, GameVariant};
 use crate::shared::PokerError;
-use super::winner::evaluate_and_determine_winners;
-use super::payout::distribute_winnings;
+use crate::shared::constants::{HOLE_CARDS, OMAHA_HOLE_CARDS};
+use super::winner::{
+    evaluate_and_determine_winners_with_rules,
+    evaluate_and_determine_hi_lo_winners,
+    split_pots_for_runouts,
+    RunoutPots,
+};
+use super::payout::{distribute_winnings, merge_runout_winnings};
 
 /// Handle showdown - reveal cards and determine winners
 pub fn handle_showdown(
@@ -23,46 +29,94 @@ pub fn handle_showdown(
     
     msg!("[SHOWDOWN] Starting showdown for game {}", game.game_id);
     
-    // Collect player hole cards (would be revealed via Arcium MPC)
-    // For MVP, we assume cards are already decrypted
-    let mut player_hole_cards = Vec::new();
+    // Collect seats still contesting the pot
+    let mut showdown_seats = Vec::new();
     
-    for i in 0..game.player_count as usize {
+    for i in 0..game.seat_count() as usize {
         if !game.active_players[i] {
             continue;
         }
         
-        let player_state = &player_states[i];
-        
         // Skip folded players
-        if player_state.has_folded {
+        if player_states[i].has_folded {
             continue;
         }
         
-        // Use Arcium MPC to reveal encrypted hole cards
-        let hole_cards = reveal_player_cards(
-            player_state,
-            game.shuffle_session_id,
-            player_state.player,
-        )?;
-        
-        player_hole_cards.push((i as u8, hole_cards));
+        showdown_seats.push(i);
     }
     
-    // Get community cards
-    let mut community_cards = [Card::from_index(0)?; 5];
-    for i in 0..5 {
-        community_cards[i] = Card::from_index(game.community_cards[i])?;
+    // Get community cards (a second board when the hand was run twice)
+    let mut boards = vec![decode_board(&game.community_cards, game)?];
+    if game.run_it_twice {
+        boards.push(decode_board(&game.second_board, game)?);
+        msg!("[SHOWDOWN] Running it twice from card {}", game.runout_start + 1);
+    }
+    
+    // Each runout plays for an equal share of every pot
+    let runout_pots = if game.run_it_twice {
+        split_pots_for_runouts(
+            pot_manager.main_pot,
+            &pot_manager.side_pots,
+            pot_manager.side_pot_count,
+        ).to_vec()
+    } else {
+        vec![RunoutPots {
+            main_pot: pot_manager.main_pot,
+            side_pots: pot_manager.side_pots[..pot_manager.side_pot_count as usize].to_vec(),
+        }]
+    };
+    
+    // Reveal hole cards via Arcium MPC and evaluate hands for the variant
+    let mut runout_winners = Vec::new();
+    match game.variant {
+        GameVariant::TexasHoldem | GameVariant::ShortDeck => {
+            let mut player_hole_cards = Vec::new();
+            for &i in &showdown_seats {
+                let player_state = &player_states[i];
+                let hole_cards = reveal_player_cards(
+                    player_state,
+                    game.shuffle_session_id,
+                    player_state.player,
+                )?;
+                player_hole_cards.push((i as u8, hole_cards));
+            }
+            
+            for (community_cards, pots) in boards.iter().zip(&runout_pots) {
+                runout_winners.push(evaluate_and_determine_winners_with_rules(
+                    &player_hole_cards,
+                    community_cards,
+                    pots.main_pot,
+                    &pots.side_pots,
+                    pots.side_pots.len() as u8,
+                    &game.hand_rules(),
+                )?);
+            }
+        }
+        GameVariant::OmahaHiLo => {
+            let mut player_hole_cards = Vec::new();
+            for &i in &showdown_seats {
+                let player_state = &player_states[i];
+                let hole_cards = reveal_hole_cards::<OMAHA_HOLE_CARDS>(
+                    player_state,
+                    game.shuffle_session_id,
+                    player_state.player,
+                )?;
+                player_hole_cards.push((i as u8, hole_cards));
+            }
+            
+            for (community_cards, pots) in boards.iter().zip(&runout_pots) {
+                runout_winners.push(evaluate_and_determine_hi_lo_winners(
+                    &player_hole_cards,
+                    community_cards,
+                    pots.main_pot,
+                    &pots.side_pots,
+                    pots.side_pots.len() as u8,
+                )?);
+            }
+        }
     }
     
-    // Evaluate hands and determine winners
-    let winners = evaluate_and_determine_winners(
-        &player_hole_cards,
-        &community_cards,
-        pot_manager.main_pot,
-        &pot_manager.side_pots,
-        pot_manager.side_pot_count,
-    )?;
+    let winners = merge_runout_winnings(&runout_winners);
     
     // Distribute winnings
     distribute_winnings(game, player_states, &winners)?;
@@ -75,15 +129,33 @@ pub fn handle_showdown(
     Ok(())
 }
 
+/// Decode a board of community card indices for the table's variant
+pub(crate) fn decode_board(board: &[u8; 5], game: &Game) -> Result<[Card; 5]> {
+    let mut community_cards = [Card::from_index(0)?; 5];
+    for i in 0..5 {
+        community_cards[i] = Card::from_index_for_variant(board[i], game.variant)?;
+    }
+    Ok(community_cards)
+}
+
 /// Reveal player's hole cards using Arcium MPC
 pub fn reveal_player_cards(
     player_state: &PlayerState,
     session_id: [u8; 32],
     requester: Pubkey,
 ) -> Result<[Card; 2]> {
-    let mut revealed_cards = [Card::from_index(0)?; 2];
+    reveal_hole_cards::<HOLE_CARDS>(player_state, session_id, requester)
+}
+
+/// Reveal the first N hole cards of a player (2 for Hold'em, 4 for Omaha)
+pub fn reveal_hole_cards<const N: usize>(
+    player_state: &PlayerState,
+    session_id: [u8; 32],
+    requester: Pubkey,
+) -> Result<[Card; N]> {
+    let mut revealed_cards = [Card::from_index(0)?; N];
     
-    for i in 0..2 {
+    for i in 0..N {
         // Create encrypted card from player state
         let encrypted_card = crate::arcium::mpc_deal::EncryptedCard {
             encrypted_index: player_state.encrypted_hole_cards[i],
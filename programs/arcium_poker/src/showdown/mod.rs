@@ -2,19 +2,28 @@
 pub mod instruction;
 pub mod winner;
 pub mod payout;
+pub mod run_it_twice;
 
 // Export specific items
-pub use instruction::{handle_showdown, reveal_player_cards, handle_muck};
+pub use instruction::{handle_showdown, reveal_player_cards, reveal_hole_cards, handle_muck};
+pub use run_it_twice::handler as run_it_twice_handler;
 pub use winner::{
     PotWinner,
+    PotHalf,
+    HiLoHand,
+    RunoutPots,
     determine_main_pot_winners,
     determine_side_pot_winners,
     determine_all_winners,
+    split_pots_for_runouts,
     evaluate_and_determine_winners,
+    evaluate_and_determine_winners_with_rules,
+    determine_hi_lo_pot_winners,
+    determine_all_hi_lo_winners,
+    evaluate_and_determine_hi_lo_winners,
 };
 pub use payout::{
     distribute_winnings,
+    merge_runout_winnings,
     transfer_winnings_to_accounts,
-    calculate_rake,
-    distribute_with_rake,
 };
\ No newline at end of file
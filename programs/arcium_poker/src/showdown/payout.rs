@@ -43,6 +43,21 @@ pub fn distribute_winnings(
     Ok(())
 }
 
+/// Merge per-runout winnings into per-seat totals (run it twice)
+pub fn merge_runout_winnings(runouts: &[Vec<(u8, u64)>]) -> Vec<(u8, u64)> {
+    let mut merged: Vec<(u8, u64)> = Vec::new();
+    
+    for (seat_index, amount) in runouts.iter().flatten() {
+        match merged.iter_mut().find(|(seat, _)| seat == seat_index) {
+            Some((_, total)) => *total += amount,
+            None => merged.push((*seat_index, *amount)),
+        }
+    }
+    
+    merged.sort_by_key(|(seat, _)| *seat);
+    merged
+}
+
 /// Transfer winnings from game PDA to player accounts (for SOL/tokens)
 pub fn transfer_winnings_to_accounts(
     game_account: &AccountInfo,
@@ -65,37 +80,3 @@ pub fn transfer_winnings_to_accounts(
     
     Ok(())
 }
-
-/// Handle rake (house fee) - optional
-pub fn calculate_rake(pot_amount: u64, rake_percentage: u8) -> u64 {
-    // Rake is typically 2.5-5% of pot, capped at a maximum
-    let rake = (pot_amount * rake_percentage as u64) / 100;
-    let max_rake = 3_000_000; // 0.003 SOL max rake
-    rake.min(max_rake)
-}
-
-/// Distribute pot with rake
-pub fn distribute_with_rake(
-    game: &mut Game,
-    player_states: &mut [PlayerState],
-    winners: &[(u8, u64)],
-    rake_percentage: u8,
-    house_account: &AccountInfo,
-    game_account: &AccountInfo,
-) -> Result<()> {
-    // Calculate and deduct rake
-    let rake = calculate_rake(game.pot, rake_percentage);
-    
-    if rake > 0 {
-        // Transfer rake to house
-        **game_account.try_borrow_mut_lamports()? -= rake;
-        **house_account.try_borrow_mut_lamports()? += rake;
-        
-        msg!("[PAYOUT] Rake collected: {}", rake);
-    }
-    
-    // Distribute remaining pot
-    distribute_winnings(game, player_states, winners)?;
-    
-    Ok(())
-}
\ No newline at end of file
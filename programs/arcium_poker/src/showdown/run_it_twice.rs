@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::player::state::SeatedPlayers;
+use crate::game::flow::check_all_players_all_in;
+use crate::types::GameStage;
+use crate::shared::PokerError;
+
+/// Vote to run the remaining board twice
+///
+/// Only allowed once every remaining player is all-in (or one player has
+/// covered everyone) before the river. When all remaining players agree, the
+/// rest of the board is dealt twice and each pot is split between the runouts.
+/// Remaining accounts: PlayerState accounts for every occupied seat, in any order.
+pub fn handler(ctx: Context<crate::PlayerAction>, agree: bool) -> Result<()> {
+    let game_key = ctx.accounts.game.key();
+    let game = &mut ctx.accounts.game;
+    let voter = &ctx.accounts.player_state;
+    
+    require!(
+        matches!(game.stage, GameStage::PreFlop | GameStage::Flop | GameStage::Turn),
+        PokerError::InvalidGameStage
+    );
+    require!(!game.run_it_twice, PokerError::InvalidAction);
+    require!(
+        game.active_players[voter.seat_index as usize] && !voter.has_folded,
+        PokerError::PlayerNotInGame
+    );
+    
+    // Load every seat so we can confirm no more betting is possible
+    let mut seated = SeatedPlayers::load(ctx.remaining_accounts, game, &game_key)?;
+    seated.states[voter.seat_index as usize] = (**voter).clone();
+    let player_states = seated.states;
+    
+    require!(
+        check_all_players_all_in(game, &player_states),
+        PokerError::InvalidAction
+    );
+    
+    game.run_it_twice_votes[voter.seat_index as usize] = agree;
+    
+    msg!(
+        "[RUN IT TWICE] Seat {} voted {}",
+        voter.seat_index,
+        if agree { "yes" } else { "no" }
+    );
+    
+    // Runs twice only when every player still in the hand agrees
+    let all_agreed = (0..game.seat_count() as usize)
+        .filter(|&seat| game.active_players[seat] && !player_states[seat].has_folded)
+        .all(|seat| game.run_it_twice_votes[seat]);
+    
+    if all_agreed {
+        game.run_it_twice = true;
+        game.runout_start = game.community_cards_revealed;
+        
+        // Cards already on the board are shared by both runouts
+        let shared = game.runout_start as usize;
+        let community_cards = game.community_cards;
+        game.second_board[..shared].copy_from_slice(&community_cards[..shared]);
+        
+        msg!(
+            "[RUN IT TWICE] All players agreed. Running twice from card {}",
+            game.runout_start + 1
+        );
+    }
+    
+    Ok(())
+}
+
+// Uses the PlayerAction accounts struct in lib.rs
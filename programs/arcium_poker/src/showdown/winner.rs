@@ -1,18 +1,38 @@
 use anchor_lang::prelude::*;
-use crate::cards::evaluator::{EvaluatedHand, evaluate_best_hand};
+use crate::cards::evaluator::{
+    EvaluatedHand,
+    HandRules,
+    LowHand,
+    evaluate_best_hand_with_rules,
+    evaluate_omaha_high,
+    evaluate_omaha_low,
+};
 use crate::cards::deck::Card;
 use crate::betting::state::SidePot;
-use crate::shared::constants::MAX_PLAYERS;
+use crate::shared::constants::{MAX_PLAYERS, OMAHA_HOLE_CARDS};
 use crate::shared::PokerError;
 
+/// Which part of a pot a winner was awarded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PotHalf {
+    Whole,  // Entire pot (Hold'em, or hi-lo with no qualifying low)
+    High,   // High half of a hi-lo split pot
+    Low,    // Low half of a hi-lo split pot
+}
+
 /// Winner information for a pot
 #[derive(Clone, Debug)]
 pub struct PotWinner {
     pub seat_index: u8,
     pub hand: EvaluatedHand,
+    pub low_hand: Option<LowHand>, // Winning low (only for PotHalf::Low)
     pub share: u64,  // Amount won from this pot
+    pub half: PotHalf,
 }
 
+/// Hand for hi-lo showdowns: (seat_index, high hand, qualifying low if any)
+pub type HiLoHand = (u8, EvaluatedHand, Option<LowHand>);
+
 /// Determine winners for main pot
 pub fn determine_main_pot_winners(
     player_hands: &[(u8, EvaluatedHand)], // (seat_index, hand)
@@ -45,7 +65,9 @@ pub fn determine_main_pot_winners(
         .map(|(i, (seat, hand))| PotWinner {
             seat_index: *seat,
             hand: *hand,
+            low_hand: None,
             share: if i == 0 { share + remainder } else { share },
+            half: PotHalf::Whole,
         })
         .collect()
 }
@@ -88,7 +110,129 @@ pub fn determine_all_winners(
         total_winnings[winner.seat_index as usize] += winner.share;
     }
     
-    // Convert to vec of (seat, winnings) for non-zero amounts
+    collect_winnings(&total_winnings)
+}
+
+/// Determine winners for a hi-lo split pot
+/// 
+/// The pot is halved between the best high and the best 8-or-better low, with
+/// the odd chip going to the high half. Tied lows split the low half (quartering).
+/// If nobody qualifies for low, the high hand scoops the whole pot.
+pub fn determine_hi_lo_pot_winners(
+    player_hands: &[HiLoHand],
+    pot_amount: u64,
+) -> Vec<PotWinner> {
+    if player_hands.is_empty() {
+        return Vec::new();
+    }
+    
+    let high_hands: Vec<(u8, EvaluatedHand)> = player_hands
+        .iter()
+        .map(|(seat, hand, _)| (*seat, *hand))
+        .collect();
+    
+    // Best low is the smallest qualifying low
+    let best_low = match player_hands.iter().filter_map(|(_, _, low)| *low).min() {
+        Some(low) => low,
+        None => return determine_main_pot_winners(&high_hands, pot_amount),
+    };
+    
+    let low_amount = pot_amount / 2;
+    let high_amount = pot_amount - low_amount;
+    
+    let mut winners = determine_main_pot_winners(&high_hands, high_amount);
+    for winner in winners.iter_mut() {
+        winner.half = PotHalf::High;
+    }
+    
+    // Find all players with the best low (for quartering)
+    let low_winners: Vec<&HiLoHand> = player_hands
+        .iter()
+        .filter(|(_, _, low)| *low == Some(best_low))
+        .collect();
+    
+    let share = low_amount / low_winners.len() as u64;
+    let remainder = low_amount % low_winners.len() as u64;
+    
+    winners.extend(
+        low_winners
+            .into_iter()
+            .enumerate()
+            .map(|(i, (seat, hand, low))| PotWinner {
+                seat_index: *seat,
+                hand: *hand,
+                low_hand: *low,
+                share: if i == 0 { share + remainder } else { share },
+                half: PotHalf::Low,
+            }),
+    );
+    
+    winners
+}
+
+/// Determine all winners for hi-lo (main pot + side pots, each split high/low)
+pub fn determine_all_hi_lo_winners(
+    player_hands: &[HiLoHand],
+    main_pot: u64,
+    side_pots: &[SidePot],
+    side_pot_count: u8,
+) -> Vec<(u8, u64)> { // Returns (seat_index, total_winnings)
+    let mut total_winnings = [0u64; MAX_PLAYERS];
+    
+    for side_pot in side_pots.iter().take(side_pot_count as usize) {
+        let eligible_hands: Vec<HiLoHand> = player_hands
+            .iter()
+            .filter(|(seat, _, _)| side_pot.is_eligible(*seat as usize))
+            .copied()
+            .collect();
+        
+        for winner in determine_hi_lo_pot_winners(&eligible_hands, side_pot.amount) {
+            total_winnings[winner.seat_index as usize] += winner.share;
+        }
+    }
+    
+    for winner in determine_hi_lo_pot_winners(player_hands, main_pot) {
+        total_winnings[winner.seat_index as usize] += winner.share;
+    }
+    
+    collect_winnings(&total_winnings)
+}
+
+/// Pots awarded on one runout of a run-it-twice hand
+#[derive(Clone, Debug)]
+pub struct RunoutPots {
+    pub main_pot: u64,
+    pub side_pots: Vec<SidePot>,
+}
+
+/// Split every pot between two runouts (odd chip goes to the first runout)
+pub fn split_pots_for_runouts(
+    main_pot: u64,
+    side_pots: &[SidePot],
+    side_pot_count: u8,
+) -> [RunoutPots; 2] {
+    let halve = |amount: u64| (amount - amount / 2, amount / 2);
+    
+    let (first_main, second_main) = halve(main_pot);
+    let mut first = RunoutPots { main_pot: first_main, side_pots: Vec::new() };
+    let mut second = RunoutPots { main_pot: second_main, side_pots: Vec::new() };
+    
+    for side_pot in side_pots.iter().take(side_pot_count as usize) {
+        let (first_amount, second_amount) = halve(side_pot.amount);
+        
+        let mut pot = *side_pot;
+        pot.amount = first_amount;
+        first.side_pots.push(pot);
+        
+        pot.amount = second_amount;
+        second.side_pots.push(pot);
+    }
+    
+    [first, second]
+}
+
+/// Convert per-seat totals to vec of (seat, winnings) for non-zero amounts
+fn collect_winnings(total_winnings: &[u64; MAX_PLAYERS]) -> Vec<(u8, u64)> {
     total_winnings
         .iter()
         .enumerate()
@@ -104,12 +248,31 @@ pub fn evaluate_and_determine_winners(
     main_pot: u64,
     side_pots: &[SidePot],
     side_pot_count: u8,
+) -> Result<Vec<(u8, u64)>> {
+    evaluate_and_determine_winners_with_rules(
+        player_hole_cards,
+        community_cards,
+        main_pot,
+        side_pots,
+        side_pot_count,
+        &HandRules::default(),
+    )
+}
+
+/// Evaluate all player hands under the table's ranking rules and determine winners
+pub fn evaluate_and_determine_winners_with_rules(
+    player_hole_cards: &[(u8, [Card; 2])], // (seat_index, hole_cards)
+    community_cards: &[Card; 5],
+    main_pot: u64,
+    side_pots: &[SidePot],
+    side_pot_count: u8,
+    rules: &HandRules,
 ) -> Result<Vec<(u8, u64)>> {
     // Evaluate all hands
     let mut evaluated_hands = Vec::new();
     
     for (seat, hole_cards) in player_hole_cards {
-        let hand = evaluate_best_hand(hole_cards, community_cards)?;
+        let hand = evaluate_best_hand_with_rules(hole_cards, community_cards, rules)?;
         evaluated_hands.push((*seat, hand));
         
         msg!(
@@ -135,4 +298,185 @@ pub fn evaluate_and_determine_winners(
     }
     
     Ok(winners)
-}
\ No newline at end of file
+}
+
+/// Evaluate all Omaha Hi-Lo hands and determine winners
+pub fn evaluate_and_determine_hi_lo_winners(
+    player_hole_cards: &[(u8, [Card; OMAHA_HOLE_CARDS])], // (seat_index, hole_cards)
+    community_cards: &[Card; 5],
+    main_pot: u64,
+    side_pots: &[SidePot],
+    side_pot_count: u8,
+) -> Result<Vec<(u8, u64)>> {
+    let mut evaluated_hands: Vec<HiLoHand> = Vec::new();
+    
+    for (seat, hole_cards) in player_hole_cards {
+        let high = evaluate_omaha_high(hole_cards, community_cards)?;
+        let low = evaluate_omaha_low(hole_cards, community_cards);
+        evaluated_hands.push((*seat, high, low));
+        
+        msg!(
+            "[SHOWDOWN] Seat {} high: {:?} (primary: {}), low: {:?}",
+            seat,
+            high.rank,
+            high.primary_value,
+            low.map(|l| l.ranks)
+        );
+    }
+    
+    let winners = determine_all_hi_lo_winners(
+        &evaluated_hands,
+        main_pot,
+        side_pots,
+        side_pot_count,
+    );
+    
+    for (seat, amount) in &winners {
+        msg!("[SHOWDOWN] Seat {} wins {}", seat, amount);
+    }
+    
+    Ok(winners)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{HandRank, Rank, Suit};
+    use crate::showdown::payout::merge_runout_winnings;
+    
+    fn high(rank: HandRank, primary: u8) -> EvaluatedHand {
+        EvaluatedHand::new(rank, primary, 0, [0; 5])
+    }
+    
+    fn low(ranks: [u8; 5]) -> Option<LowHand> {
+        Some(LowHand { ranks })
+    }
+    
+    #[test]
+    fn test_hi_lo_scoop_without_low() {
+        let hands: Vec<HiLoHand> = vec![
+            (0, high(HandRank::Flush, 14), None),
+            (1, high(HandRank::OnePair, 10), None),
+        ];
+        
+        let winners = determine_hi_lo_pot_winners(&hands, 101);
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].seat_index, 0);
+        assert_eq!(winners[0].share, 101);
+        assert_eq!(winners[0].half, PotHalf::Whole);
+    }
+    
+    #[test]
+    fn test_hi_lo_split_odd_chip_to_high() {
+        let hands: Vec<HiLoHand> = vec![
+            (0, high(HandRank::Flush, 14), None),
+            (1, high(HandRank::OnePair, 10), low([7, 5, 4, 3, 1])),
+        ];
+        
+        let winners = determine_hi_lo_pot_winners(&hands, 101);
+        assert_eq!(winners.len(), 2);
+        assert_eq!((winners[0].seat_index, winners[0].share, winners[0].half), (0, 51, PotHalf::High));
+        assert_eq!((winners[1].seat_index, winners[1].share, winners[1].half), (1, 50, PotHalf::Low));
+    }
+    
+    #[test]
+    fn test_hi_lo_quartering() {
+        // Seat 0 wins high and ties low with seat 1: seat 0 gets 3/4, seat 1 gets 1/4
+        let hands: Vec<HiLoHand> = vec![
+            (0, high(HandRank::Straight, 5), low([5, 4, 3, 2, 1])),
+            (1, high(HandRank::OnePair, 10), low([5, 4, 3, 2, 1])),
+            (2, high(HandRank::TwoPair, 12), None),
+        ];
+        
+        let totals = determine_all_hi_lo_winners(&hands, 400, &[], 0);
+        assert_eq!(totals, vec![(0, 300), (1, 100)]);
+    }
+    
+    #[test]
+    fn test_hi_lo_side_pot_eligibility() {
+        let hands: Vec<HiLoHand> = vec![
+            (0, high(HandRank::Flush, 14), low([8, 6, 4, 2, 1])),
+            (1, high(HandRank::OnePair, 10), low([6, 5, 4, 3, 2])),
+            (2, high(HandRank::TwoPair, 12), None),
+        ];
+        
+        // Side pot only contested by seats 0 and 2
+        let mut side_pot = SidePot::new(100);
+        side_pot.add_eligible_player(0);
+        side_pot.add_eligible_player(2);
+        
+        let totals = determine_all_hi_lo_winners(&hands, 200, &[side_pot], 1);
+        // Side pot: seat 0 scoops high and low (100)
+        // Main pot: seat 0 high (100), seat 1 low (100)
+        assert_eq!(totals, vec![(0, 200), (1, 100)]);
+    }
+    
+    #[test]
+    fn test_evaluate_omaha_hi_lo() {
+        let card = |rank, suit| Card::new(suit, rank);
+        let board = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Four, Suit::Clubs),
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::King, Suit::Clubs),
+        ];
+        let players = [
+            (0u8, [
+                card(Rank::King, Suit::Hearts),
+                card(Rank::Queen, Suit::Hearts),
+                card(Rank::Nine, Suit::Clubs),
+                card(Rank::Nine, Suit::Spades),
+            ]),
+            (1u8, [
+                card(Rank::Ace, Suit::Spades),
+                card(Rank::Three, Suit::Diamonds),
+                card(Rank::Queen, Suit::Clubs),
+                card(Rank::Jack, Suit::Hearts),
+            ]),
+        ];
+        
+        let totals = evaluate_and_determine_hi_lo_winners(&players, &board, 100, &[], 0).unwrap();
+        // Seat 0 trip kings wins high, seat 1 A-3 makes 7-4-3-2-A low
+        assert_eq!(totals, vec![(0, 50), (1, 50)]);
+    }
+    
+    #[test]
+    fn test_run_it_twice_splits_each_pot() {
+        let mut side_pot = SidePot::new(151);
+        side_pot.add_eligible_player(0);
+        side_pot.add_eligible_player(1);
+        
+        // Odd chips go to the first runout
+        let [first_pots, second_pots] = split_pots_for_runouts(101, &[side_pot], 1);
+        assert_eq!((first_pots.main_pot, second_pots.main_pot), (51, 50));
+        assert_eq!((first_pots.side_pots[0].amount, second_pots.side_pots[0].amount), (76, 75));
+        assert!(second_pots.side_pots[0].is_eligible(1));
+        
+        // Seat 0 wins the first runout, seat 1 the second
+        let first = vec![
+            (0, high(HandRank::Straight, 9)),
+            (1, high(HandRank::OnePair, 10)),
+        ];
+        let second = vec![
+            (0, high(HandRank::OnePair, 4)),
+            (1, high(HandRank::TwoPair, 10)),
+        ];
+        
+        let winnings = merge_runout_winnings(&[
+            determine_all_winners(
+                &first,
+                first_pots.main_pot,
+                &first_pots.side_pots,
+                first_pots.side_pots.len() as u8,
+            ),
+            determine_all_winners(
+                &second,
+                second_pots.main_pot,
+                &second_pots.side_pots,
+                second_pots.side_pots.len() as u8,
+            ),
+        ]);
+        assert_eq!(winnings, vec![(0, 127), (1, 125)]);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::shared::PokerError;
 
 /// Conversion rate configuration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
@@ -30,6 +31,14 @@ pub fn tokens_to_chips(token_amount: u64, rate: &ConversionRate) -> u64 {
     token_amount / rate.tokens_per_chip
 }
 
+/// Chips credited for tokens that reached the escrow
+/// On a transfer-fee mint this is less than the chips paid for, rounded down
+pub fn received_tokens_to_chips(received: u64, rate: &ConversionRate) -> Result<u64> {
+    let credited = tokens_to_chips(received, rate);
+    require!(credited > 0, PokerError::InsufficientBalance);
+    Ok(credited)
+}
+
 /// Convert chips to tokens
 pub fn chips_to_tokens(chip_amount: u64, rate: &ConversionRate) -> u64 {
     // Convert chips to tokens based on rate
@@ -90,4 +99,37 @@ mod tests {
         // 10 chips = 10 SOL
         assert_eq!(chips_to_tokens(10, &rate), 10_000_000_000);
     }
+    
+    #[test]
+    fn test_conversion_rate_from_mint_decimals() {
+        let rate = get_conversion_rate(6);
+        assert_eq!(rate.tokens_per_chip, 1_000_000);
+        
+        // Buy-ins and cash-outs convert exactly
+        assert_eq!(calculate_buyin_tokens(250, &rate), 250_000_000);
+        assert_eq!(tokens_to_chips(calculate_cashout_tokens(250, &rate), &rate), 250);
+        
+        // Partial chips are never credited
+        assert_eq!(tokens_to_chips(249_999_999, &rate), 249);
+        assert_eq!(tokens_to_chips(999_999, &rate), 0);
+        
+        // A zero-decimal mint is one token per chip
+        assert_eq!(get_conversion_rate(0).tokens_per_chip, 1);
+    }
+    
+    #[test]
+    fn test_transfer_fee_credits_received_tokens() {
+        let rate = get_conversion_rate(6);
+        
+        // 100 chips sent with a 1% fee: 99 whole chips reached the escrow
+        let sent = calculate_buyin_tokens(100, &rate);
+        let received = sent - sent / 100;
+        assert_eq!(received_tokens_to_chips(received, &rate).unwrap(), 99);
+        
+        // A fee that eats into a chip rounds the credit down
+        assert_eq!(received_tokens_to_chips(sent - 1, &rate).unwrap(), 99);
+        
+        // Nothing is credited for less than a chip
+        assert!(received_tokens_to_chips(999_999, &rate).is_err());
+    }
 }
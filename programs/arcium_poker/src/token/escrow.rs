@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, CloseAccount, TokenAccount, TokenInterface, TransferChecked};
 use crate::shared::PokerError;
+use super::routing::TokenRoute;
 
 /// Create token escrow account for game
 pub fn create_token_escrow(
@@ -17,13 +18,20 @@ pub fn create_token_escrow(
 }
 
 /// Lock tokens when player joins game
+/// Returns the tokens that actually reached the escrow, which is less than
+/// `amount` when the mint charges a transfer fee
 pub fn lock_tokens_on_join<'info>(
-    player_token_account: &Account<'info, TokenAccount>,
-    escrow_token_account: &Account<'info, TokenAccount>,
+    route: &TokenRoute<'_, 'info>,
     player_authority: &Signer<'info>,
-    token_program: &Program<'info, Token>,
     amount: u64,
-) -> Result<()> {
+) -> Result<u64> {
+    let TokenRoute {
+        player_token_account,
+        escrow_token_account,
+        token_mint: mint,
+        token_program,
+    } = *route;
+    
     // Validate token accounts
     require!(
         player_token_account.mint == escrow_token_account.mint,
@@ -36,8 +44,9 @@ pub fn lock_tokens_on_join<'info>(
     );
     
     // Transfer tokens from player to escrow
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: player_token_account.to_account_info(),
+        mint: mint.to_account_info(),
         to: escrow_token_account.to_account_info(),
         authority: player_authority.to_account_info(),
     };
@@ -45,27 +54,46 @@ pub fn lock_tokens_on_join<'info>(
     let cpi_program = token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     
-    token::transfer(cpi_ctx, amount)?;
+    let balance_before = escrow_token_account.amount;
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
+    
+    // Measure what arrived rather than trusting the sent amount
+    let received = current_balance(escrow_token_account)?.saturating_sub(balance_before);
     
     msg!(
-        "[TOKEN] Locked {} tokens from player {} to escrow",
+        "[TOKEN] Locked {} tokens from player {} to escrow ({} received)",
         amount,
-        player_authority.key()
+        player_authority.key(),
+        received
     );
     
-    Ok(())
+    Ok(received)
+}
+
+/// Read a token account's balance from its data
+/// Unlike the loaded account, this reflects transfers made earlier in the instruction
+pub fn current_balance(token_account: &InterfaceAccount<TokenAccount>) -> Result<u64> {
+    let account_info = token_account.to_account_info();
+    let data = account_info.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
 }
 
 /// Release tokens when player leaves game
+/// Tokens go from the route's escrow to its player token account
 pub fn release_tokens_on_leave<'info>(
-    escrow_token_account: &Account<'info, TokenAccount>,
-    player_token_account: &Account<'info, TokenAccount>,
+    route: &TokenRoute<'_, 'info>,
     escrow_authority: &AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
     amount: u64,
     escrow_bump: u8,
     game_key: Pubkey,
 ) -> Result<()> {
+    let TokenRoute {
+        player_token_account,
+        escrow_token_account,
+        token_mint: mint,
+        token_program,
+    } = *route;
+    
     // Validate sufficient balance in escrow
     require!(
         escrow_token_account.amount >= amount,
@@ -81,8 +109,9 @@ pub fn release_tokens_on_leave<'info>(
     let signer = &[&seeds[..]];
     
     // Transfer tokens from escrow to player
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: escrow_token_account.to_account_info(),
+        mint: mint.to_account_info(),
         to: player_token_account.to_account_info(),
         authority: escrow_authority.clone(),
     };
@@ -90,7 +119,7 @@ pub fn release_tokens_on_leave<'info>(
     let cpi_program = token_program.to_account_info();
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     
-    token::transfer(cpi_ctx, amount)?;
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
     
     msg!(
         "[TOKEN] Released {} tokens from escrow to player",
@@ -100,23 +129,43 @@ pub fn release_tokens_on_leave<'info>(
     Ok(())
 }
 
+/// Close an empty escrow token account, returning its rent
+pub fn close_token_escrow<'info>(
+    escrow_token_account: &InterfaceAccount<'info, TokenAccount>,
+    destination: &AccountInfo<'info>,
+    escrow_authority: &AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    escrow_bump: u8,
+    game_key: Pubkey,
+) -> Result<()> {
+    let seeds = &[
+        b"token_escrow",
+        game_key.as_ref(),
+        &[escrow_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = CloseAccount {
+        account: escrow_token_account.to_account_info(),
+        destination: destination.clone(),
+        authority: escrow_authority.clone(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token_interface::close_account(cpi_ctx)?;
+    
+    msg!("[TOKEN] Closed escrow for game {}", game_key);
+    
+    Ok(())
+}
+
 /// Transfer winnings from escrow to player
 pub fn transfer_winnings<'info>(
-    escrow_token_account: &Account<'info, TokenAccount>,
-    player_token_account: &Account<'info, TokenAccount>,
+    route: &TokenRoute<'_, 'info>,
     escrow_authority: &AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
     amount: u64,
     escrow_bump: u8,
     game_key: Pubkey,
 ) -> Result<()> {
-    release_tokens_on_leave(
-        escrow_token_account,
-        player_token_account,
-        escrow_authority,
-        token_program,
-        amount,
-        escrow_bump,
-        game_key,
-    )
+    release_tokens_on_leave(route, escrow_authority, amount, escrow_bump, game_key)
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions,
+    ExtensionType,
+    StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::Mint;
+use crate::shared::PokerError;
+
+/// Mint extensions that let a third party move, freeze or block escrowed funds
+const UNSAFE_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::TransferHook,
+    ExtensionType::Pausable,
+    ExtensionType::DefaultAccountState,
+    ExtensionType::NonTransferable,
+];
+
+/// Reject mints that could freeze or drain the game escrow
+/// Classic SPL mints only need the freeze authority check
+pub fn validate_mint_safety(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    require!(
+        mint.freeze_authority.is_none(),
+        PokerError::UnsupportedMint
+    );
+    
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    
+    for extension in state.get_extension_types()? {
+        if UNSAFE_EXTENSIONS.contains(&extension) {
+            msg!("[TOKEN] Mint {} has unsafe extension {:?}", mint.key(), extension);
+            return err!(PokerError::UnsupportedMint);
+        }
+    }
+    
+    Ok(())
+}
+
@@ -2,19 +2,27 @@
 pub mod escrow;
 pub mod conversion;
 pub mod withdrawal;
+pub mod routing;
+pub mod mint;
 
 // Export specific items
 pub use escrow::{
     create_token_escrow,
     lock_tokens_on_join,
     release_tokens_on_leave,
+    current_balance,
+    close_token_escrow,
 };
 pub use conversion::{
     tokens_to_chips,
+    received_tokens_to_chips,
     chips_to_tokens,
     get_conversion_rate,
 };
+pub use routing::{TokenRoute, deposit_chips, payout_chips};
+pub use mint::validate_mint_safety;
 pub use withdrawal::{
     withdraw_chips_to_tokens,
     calculate_withdrawal_fee,
+    validate_withdrawal,
 };
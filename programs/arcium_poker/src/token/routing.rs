@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SystemTransfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::game::state::Game;
+use crate::shared::PokerError;
+use super::conversion::{chips_to_tokens, received_tokens_to_chips};
+use super::escrow::{lock_tokens_on_join, release_tokens_on_leave};
+use super::mint::validate_mint_safety;
+
+/// Token accounts used to move chips at a token-denominated table
+#[derive(Clone, Copy)]
+pub struct TokenRoute<'a, 'info> {
+    pub player_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub escrow_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> TokenRoute<'a, 'info> {
+    /// Token accounts for a token table, or None for a SOL table
+    /// Token tables must pass the game's mint and escrow, a token account of
+    /// that mint, and the token program that owns the mint (SPL Token or Token-2022)
+    pub fn for_game(
+        game: &Game,
+        player_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        escrow_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        token_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+        token_program: Option<&'a Interface<'info, TokenInterface>>,
+    ) -> Result<Option<Self>> {
+        if !game.is_token_table() {
+            return Ok(None);
+        }
+        
+        let (
+            Some(player_token_account),
+            Some(escrow_token_account),
+            Some(token_mint),
+            Some(token_program),
+        ) = (player_token_account, escrow_token_account, token_mint, token_program)
+        else {
+            return err!(PokerError::InvalidTokenAccounts);
+        };
+        
+        require_keys_eq!(
+            token_mint.key(),
+            game.token_mint,
+            PokerError::InvalidTokenAccounts
+        );
+        require_keys_eq!(
+            *token_mint.to_account_info().owner,
+            token_program.key(),
+            PokerError::InvalidTokenAccounts
+        );
+        
+        require_keys_eq!(
+            escrow_token_account.key(),
+            game.token_escrow,
+            PokerError::InvalidTokenAccounts
+        );
+        require_keys_eq!(
+            player_token_account.mint,
+            game.token_mint,
+            PokerError::InvalidTokenAccounts
+        );
+        
+        Ok(Some(Self {
+            player_token_account,
+            escrow_token_account,
+            token_mint,
+            token_program,
+        }))
+    }
+}
+
+/// Move chips from a player into the game escrow
+/// SOL tables transfer lamports to the game PDA, token tables transfer
+/// tokens to the escrow token account at the game's conversion rate.
+/// Returns the chips to credit: on a transfer-fee mint only the tokens that
+/// reached the escrow count, rounded down to whole chips. The credited chips
+/// are added to the game's escrow ledger.
+pub fn deposit_chips<'info>(
+    game: &mut Account<'info, Game>,
+    player: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    route: Option<TokenRoute<'_, 'info>>,
+    chips: u64,
+) -> Result<u64> {
+    match route {
+        Some(route) => {
+            if game.reject_unsafe_mint {
+                validate_mint_safety(route.token_mint)?;
+            }
+            
+            let received = lock_tokens_on_join(
+                &route,
+                player,
+                chips_to_tokens(chips, &game.conversion_rate),
+            )?;
+            
+            let credited = received_tokens_to_chips(received, &game.conversion_rate)?;
+            game.escrowed_chips += credited;
+            Ok(credited)
+        }
+        None => {
+            transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    SystemTransfer {
+                        from: player.to_account_info(),
+                        to: game.to_account_info(),
+                    },
+                ),
+                chips,
+            )?;
+            game.escrowed_chips += chips;
+            Ok(chips)
+        }
+    }
+}
+
+/// Pay chips out of the game escrow
+/// SOL tables move lamports from the game PDA to `recipient`, token tables
+/// release tokens to the route's token account, signed by the escrow authority.
+/// The chips are taken off the game's escrow ledger.
+pub fn payout_chips<'info>(
+    game: &mut Account<'info, Game>,
+    recipient: &AccountInfo<'info>,
+    escrow_authority: Option<&AccountInfo<'info>>,
+    route: Option<TokenRoute<'_, 'info>>,
+    chips: u64,
+) -> Result<()> {
+    game.escrowed_chips = game.escrowed_chips
+        .checked_sub(chips)
+        .ok_or(PokerError::EscrowImbalance)?;
+    
+    match route {
+        Some(route) => {
+            let escrow_authority = escrow_authority.ok_or(PokerError::InvalidTokenAccounts)?;
+            let expected_authority = Pubkey::create_program_address(
+                &[b"token_escrow", game.key().as_ref(), &[game.escrow_bump]],
+                &crate::ID,
+            )
+            .map_err(|_| PokerError::InvalidTokenAccounts)?;
+            require_keys_eq!(
+                escrow_authority.key(),
+                expected_authority,
+                PokerError::InvalidTokenAccounts
+            );
+            
+            release_tokens_on_leave(
+                &route,
+                escrow_authority,
+                chips_to_tokens(chips, &game.conversion_rate),
+                game.escrow_bump,
+                game.key(),
+            )
+        }
+        None => {
+            // Transfer lamports from game PDA to recipient
+            **game.to_account_info().try_borrow_mut_lamports()? -= chips;
+            **recipient.try_borrow_mut_lamports()? += chips;
+            Ok(())
+        }
+    }
+}
@@ -1,23 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
 use crate::player::state::PlayerState;
 use crate::game::state::Game;
 use crate::shared::PokerError;
-use super::conversion::{ConversionRate, chips_to_tokens};
+use super::conversion::chips_to_tokens;
 use super::escrow::release_tokens_on_leave;
+use super::routing::TokenRoute;
 
 /// Withdraw chips to tokens
+/// Tokens are released to the route's player token account at the game's conversion rate
 pub fn withdraw_chips_to_tokens<'info>(
     player_state: &mut PlayerState,
-    game_key: Pubkey,
-    escrow_token_account: &Account<'info, TokenAccount>,
-    player_token_account: &Account<'info, TokenAccount>,
+    game: &Account<'info, Game>,
+    route: &TokenRoute<'_, 'info>,
     escrow_authority: &AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
     chip_amount: u64,
-    conversion_rate: &ConversionRate,
-    escrow_bump: u8,
-    rake_percentage: u8,
+    fee_bps: u16,
 ) -> Result<()> {
     // Validate player has sufficient chips
     require!(
@@ -26,23 +23,21 @@ pub fn withdraw_chips_to_tokens<'info>(
     );
     
     // Calculate token amount
-    let token_amount = chips_to_tokens(chip_amount, conversion_rate);
+    let token_amount = chips_to_tokens(chip_amount, &game.conversion_rate);
     
     // Calculate and deduct fee if applicable
-    let (net_amount, fee) = calculate_withdrawal_fee(token_amount, rake_percentage);
+    let (net_amount, fee) = calculate_withdrawal_fee(token_amount, fee_bps);
     
     // Deduct chips from player
     player_state.chip_stack -= chip_amount;
     
     // Transfer tokens to player
     release_tokens_on_leave(
-        escrow_token_account,
-        player_token_account,
+        route,
         escrow_authority,
-        token_program,
         net_amount,
-        escrow_bump,
-        game_key,
+        game.escrow_bump,
+        game.key(),
     )?;
     
     msg!(
@@ -56,28 +51,25 @@ pub fn withdraw_chips_to_tokens<'info>(
     Ok(())
 }
 
-/// Calculate withdrawal fee (rake)
-pub fn calculate_withdrawal_fee(amount: u64, rake_percentage: u8) -> (u64, u64) {
-    if rake_percentage == 0 {
+/// Calculate withdrawal fee (in basis points, e.g., 100 = 1%)
+pub fn calculate_withdrawal_fee(amount: u64, fee_bps: u16) -> (u64, u64) {
+    if fee_bps == 0 {
         return (amount, 0);
     }
     
-    let fee = (amount * rake_percentage as u64) / 100;
+    let fee = (amount as u128 * fee_bps as u128 / 10_000) as u64;
     let net_amount = amount.saturating_sub(fee);
     
     (net_amount, fee)
 }
 
 /// Instant settlement after hand completion
+/// `routes[i]` carries the token account of `player_states[i]`
 pub fn settle_hand_winnings<'info>(
     player_states: &mut [PlayerState],
-    game_key: Pubkey,
-    escrow_token_account: &Account<'info, TokenAccount>,
-    player_token_accounts: &[Account<'info, TokenAccount>],
+    game: &Account<'info, Game>,
+    routes: &[TokenRoute<'_, 'info>],
     escrow_authority: &AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
-    conversion_rate: &ConversionRate,
-    escrow_bump: u8,
 ) -> Result<()> {
     for (i, player_state) in player_states.iter_mut().enumerate() {
         if player_state.chip_stack == 0 {
@@ -86,16 +78,15 @@ pub fn settle_hand_winnings<'info>(
         
         // Auto-cashout players who are leaving
         if player_state.status == crate::types::PlayerStatus::Left {
-            let token_amount = chips_to_tokens(player_state.chip_stack, conversion_rate);
+            let token_amount = chips_to_tokens(player_state.chip_stack, &game.conversion_rate);
+            let route = routes.get(i).ok_or(PokerError::InvalidTokenAccounts)?;
             
             release_tokens_on_leave(
-                escrow_token_account,
-                &player_token_accounts[i],
+                route,
                 escrow_authority,
-                token_program,
                 token_amount,
-                escrow_bump,
-                game_key,
+                game.escrow_bump,
+                game.key(),
             )?;
             
             player_state.chip_stack = 0;
@@ -130,11 +121,5 @@ pub fn validate_withdrawal(
         PokerError::InsufficientChips
     );
     
-    // Cannot withdraw if in active hand
-    require!(
-        !player_state.has_cards || player_state.has_folded,
-        PokerError::InvalidAction
-    );
-    
     Ok(())
 }
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::shared::constants::MAX_TREASURY_SIGNERS;
+use crate::shared::PokerError;
+
+/// Create the house treasury
+/// 
+/// Only the program's upgrade authority can call this, and it becomes the
+/// admin. `threshold` members of `signers` can also
+/// approve withdrawals together; a threshold of 0 leaves approval to the admin.
+pub fn handler(
+    ctx: Context<crate::InitializeTreasury>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        signers.len() <= MAX_TREASURY_SIGNERS && threshold as usize <= signers.len(),
+        PokerError::InvalidTreasuryConfig
+    );
+    
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            *signer != Pubkey::default() && !signers[..i].contains(signer),
+            PokerError::InvalidTreasuryConfig
+        );
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.admin = ctx.accounts.admin.key();
+    treasury.signers = [Pubkey::default(); MAX_TREASURY_SIGNERS];
+    treasury.signers[..signers.len()].copy_from_slice(&signers);
+    treasury.signer_count = signers.len() as u8;
+    treasury.threshold = threshold;
+    treasury.bump = ctx.bumps.treasury;
+    
+    msg!(
+        "[TREASURY] Initialized with admin {} and {}-of-{} signers",
+        treasury.admin,
+        threshold,
+        signers.len()
+    );
+    
+    Ok(())
+}
+
+/// Open the treasury ledger for a denomination
+/// 
+/// `mint` is the default pubkey for SOL. Token ledgers create a vault token
+/// account owned by the treasury.
+pub fn open_ledger(ctx: Context<crate::OpenTreasuryLedger>, mint: Pubkey) -> Result<()> {
+    let ledger = &mut ctx.accounts.ledger;
+    
+    ledger.mint = mint;
+    ledger.total_collected = 0;
+    ledger.total_withdrawn = 0;
+    ledger.reserved = 0;
+    ledger.bump = ctx.bumps.ledger;
+    
+    match (&ctx.accounts.token_mint, &ctx.accounts.vault) {
+        (None, None) => {
+            require!(mint == Pubkey::default(), PokerError::InvalidTokenAccounts);
+            ledger.vault = Pubkey::default();
+        }
+        (Some(token_mint), Some(vault)) => {
+            require_keys_eq!(token_mint.key(), mint, PokerError::InvalidTokenAccounts);
+            ledger.vault = vault.key();
+        }
+        _ => return err!(PokerError::InvalidTokenAccounts),
+    }
+    
+    msg!("[TREASURY] Opened ledger for mint {}", mint);
+    
+    Ok(())
+}
+
+// Uses the InitializeTreasury and OpenTreasuryLedger accounts structs in lib.rs
@@ -0,0 +1,12 @@
+// House treasury module - governed destination for all rake
+pub mod state;
+pub mod initialize;
+pub mod withdraw;
+
+pub use state::*;
+
+// Export the handler functions
+pub use initialize::{handler as initialize_treasury_handler, open_ledger as open_treasury_ledger_handler};
+pub use withdraw::handler as withdraw_treasury_handler;
+
+// Note: Treasury accounts structs are in lib.rs at crate root
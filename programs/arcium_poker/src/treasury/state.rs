@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use crate::shared::constants::MAX_TREASURY_SIGNERS;
+use crate::shared::PokerError;
+
+/// House treasury (single program-wide PDA)
+/// Holds swept SOL rake and owns the per-mint token vaults. Funds only leave
+/// through `withdraw_treasury`, approved by the admin or an M-of-N signer set
+#[account]
+#[derive(Default)]
+pub struct Treasury {
+    /// Admin who can approve withdrawals alone
+    pub admin: Pubkey,
+    
+    /// Signer set for M-of-N approval
+    pub signers: [Pubkey; MAX_TREASURY_SIGNERS],
+    
+    /// Members of the signer set in use
+    pub signer_count: u8,
+    
+    /// Approvals needed from the signer set (0 = admin only)
+    pub threshold: u8,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Treasury {
+    /// Calculate space needed for Treasury account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        (32 * MAX_TREASURY_SIGNERS) + // signers
+        1 + // signer_count
+        1 + // threshold
+        1; // bump
+    
+    /// Check if a withdrawal signed by `approvers` may go ahead
+    /// Needs the admin, or `threshold` distinct members of the signer set
+    pub fn is_approved(&self, approvers: &[Pubkey]) -> bool {
+        if approvers.contains(&self.admin) {
+            return true;
+        }
+        
+        let approvals = self.signers[..self.signer_count as usize]
+            .iter()
+            .filter(|signer| approvers.contains(signer))
+            .count();
+        
+        self.threshold > 0 && approvals >= self.threshold as usize
+    }
+}
+
+/// Treasury totals for one denomination
+/// SOL uses the default mint and is held by the Treasury PDA; tokens are
+/// held in the ledger's vault token account
+#[account]
+#[derive(Default)]
+pub struct TreasuryLedger {
+    /// Mint of the denomination (default for SOL)
+    pub mint: Pubkey,
+    
+    /// Token vault owned by the treasury (default for SOL)
+    pub vault: Pubkey,
+    
+    /// Rake received over the treasury's lifetime
+    pub total_collected: u64,
+    
+    /// Amount released through approved withdrawals and reward claims
+    pub total_withdrawn: u64,
+    
+    /// Rakeback and affiliate balances accrued but not yet claimed
+    pub reserved: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TreasuryLedger {
+    /// Calculate space needed for TreasuryLedger account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // vault
+        8 + // total_collected
+        8 + // total_withdrawn
+        8 + // reserved
+        1; // bump
+    
+    /// Check if the ledger tracks SOL rather than a token
+    pub fn is_sol(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+    
+    /// Amount held, not yet withdrawn and not owed to players as rewards
+    pub fn balance(&self) -> u64 {
+        (self.total_collected - self.total_withdrawn).saturating_sub(self.reserved)
+    }
+    
+    /// Record rake received
+    pub fn record_deposit(&mut self, amount: u64) {
+        self.total_collected += amount;
+    }
+    
+    /// Record an approved withdrawal
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.balance(), PokerError::InsufficientBalance);
+        self.total_withdrawn += amount;
+        Ok(())
+    }
+    
+    /// Set aside accrued rakeback and affiliate balances
+    /// Only rake the treasury has received and not yet committed can be reserved
+    pub fn reserve(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.balance(), PokerError::InsufficientBalance);
+        self.reserved += amount;
+        Ok(())
+    }
+    
+    /// Record a reward claim paid out of the reserve
+    pub fn record_claim(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.reserved && amount <= self.total_collected - self.total_withdrawn,
+            PokerError::InsufficientBalance
+        );
+        self.reserved -= amount;
+        self.total_withdrawn += amount;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn two_of_three() -> (Treasury, [Pubkey; 3]) {
+        let members = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut treasury = Treasury {
+            admin: Pubkey::new_unique(),
+            signer_count: 3,
+            threshold: 2,
+            ..Default::default()
+        };
+        treasury.signers[..3].copy_from_slice(&members);
+        (treasury, members)
+    }
+    
+    #[test]
+    fn test_m_of_n_approval() {
+        let (treasury, members) = two_of_three();
+        
+        // The admin approves alone
+        assert!(treasury.is_approved(&[treasury.admin]));
+        
+        assert!(!treasury.is_approved(&[members[0]]));
+        assert!(treasury.is_approved(&[members[0], members[2]]));
+        assert!(treasury.is_approved(&members));
+    }
+    
+    #[test]
+    fn test_duplicate_and_outside_signers_do_not_count() {
+        let (treasury, members) = two_of_three();
+        
+        // One member signing twice is one approval
+        assert!(!treasury.is_approved(&[members[1], members[1]]));
+        
+        // Non-members and empty signer slots add nothing
+        assert!(!treasury.is_approved(&[members[1], Pubkey::new_unique()]));
+        assert!(!treasury.is_approved(&[members[1], Pubkey::default()]));
+    }
+    
+    #[test]
+    fn test_zero_threshold_is_admin_only() {
+        let (mut treasury, members) = two_of_three();
+        treasury.threshold = 0;
+        
+        assert!(!treasury.is_approved(&members));
+        assert!(!treasury.is_approved(&[]));
+        assert!(treasury.is_approved(&[treasury.admin]));
+    }
+    
+    #[test]
+    fn test_reserve_bounded_by_balance() {
+        let mut ledger = TreasuryLedger { total_collected: 1_000, total_withdrawn: 300, ..Default::default() };
+        
+        ledger.reserve(500).unwrap();
+        assert_eq!(ledger.balance(), 200);
+        
+        // Rewards cannot be promised out of rake the treasury never received
+        assert!(ledger.reserve(201).is_err());
+        ledger.reserve(200).unwrap();
+        assert_eq!(ledger.balance(), 0);
+    }
+}
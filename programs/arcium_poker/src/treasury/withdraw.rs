@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use super::state::{Treasury, TreasuryLedger};
+use crate::shared::PokerError;
+use crate::token::TokenRoute;
+
+/// Release funds from the treasury
+/// 
+/// Approved when the admin signs, or when enough members of the signer set
+/// sign. Co-signers are passed as remaining accounts. SOL goes to `recipient`,
+/// tokens go from the ledger's vault to a token account owned by `recipient`.
+pub fn handler(ctx: Context<crate::WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, PokerError::InvalidBetAmount);
+    
+    let mut approvers = vec![ctx.accounts.authority.key()];
+    approvers.extend(
+        ctx.remaining_accounts
+            .iter()
+            .filter(|account_info| account_info.is_signer)
+            .map(|account_info| account_info.key()),
+    );
+    require!(
+        ctx.accounts.treasury.is_approved(&approvers),
+        PokerError::TreasuryNotApproved
+    );
+    
+    ctx.accounts.ledger.record_withdrawal(amount)?;
+    
+    let route = ledger_route(
+        &ctx.accounts.ledger,
+        &ctx.accounts.recipient.key(),
+        ctx.accounts.recipient_token_account.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    release_funds(
+        &ctx.accounts.treasury,
+        &ctx.accounts.recipient.to_account_info(),
+        route,
+        amount,
+    )?;
+    
+    msg!(
+        "[TREASURY] Withdrew {} of mint {} to {} (balance: {})",
+        amount,
+        ctx.accounts.ledger.mint,
+        ctx.accounts.recipient.key(),
+        ctx.accounts.ledger.balance()
+    );
+    
+    Ok(())
+}
+
+/// Token accounts to pay out of a treasury ledger, or None for the SOL ledger
+/// Token ledgers must pass their vault, mint and a token account owned by `recipient`
+pub(crate) fn ledger_route<'a, 'info>(
+    ledger: &TreasuryLedger,
+    recipient: &Pubkey,
+    recipient_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    token_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    token_program: Option<&'a Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenRoute<'a, 'info>>> {
+    if ledger.is_sol() {
+        return Ok(None);
+    }
+    
+    let (Some(recipient_token_account), Some(vault), Some(token_mint), Some(token_program)) =
+        (recipient_token_account, vault, token_mint, token_program)
+    else {
+        return err!(PokerError::InvalidTokenAccounts);
+    };
+    
+    require_keys_eq!(vault.key(), ledger.vault, PokerError::InvalidTokenAccounts);
+    require_keys_eq!(token_mint.key(), ledger.mint, PokerError::InvalidTokenAccounts);
+    require_keys_eq!(recipient_token_account.owner, *recipient, PokerError::InvalidTokenAccounts);
+    
+    Ok(Some(TokenRoute {
+        player_token_account: recipient_token_account,
+        escrow_token_account: vault,
+        token_mint,
+        token_program,
+    }))
+}
+
+/// Pay out of the treasury; the caller records it on the ledger first
+/// SOL leaves the Treasury PDA (rent stays), tokens leave the vault signed by the treasury
+pub(crate) fn release_funds<'info>(
+    treasury: &Account<'info, Treasury>,
+    recipient: &AccountInfo<'info>,
+    route: Option<TokenRoute<'_, 'info>>,
+    amount: u64,
+) -> Result<()> {
+    match route {
+        Some(route) => {
+            let seeds = &[b"treasury".as_ref(), &[treasury.bump]];
+            let signer = &[&seeds[..]];
+            
+            let cpi_accounts = TransferChecked {
+                from: route.escrow_token_account.to_account_info(),
+                mint: route.token_mint.to_account_info(),
+                to: route.player_token_account.to_account_info(),
+                authority: treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                route.token_program.to_account_info(),
+                cpi_accounts,
+                signer,
+            );
+            
+            token_interface::transfer_checked(cpi_ctx, amount, route.token_mint.decimals)
+        }
+        None => {
+            **treasury.to_account_info().try_borrow_mut_lamports()? -= amount;
+            **recipient.try_borrow_mut_lamports()? += amount;
+            Ok(())
+        }
+    }
+}
+
+// Uses the WithdrawTreasury accounts struct in lib.rs
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::shared::constants::{DECK_SIZE, HOLE_CARDS, OMAHA_HOLE_CARDS, SHORT_DECK_SIZE};
 
 /// Game stage/phase
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -18,6 +19,51 @@ impl Default for GameStage {
     }
 }
 
+/// Poker variant dealt at a table
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GameVariant {
+    #[default]
+    TexasHoldem,    // 2 hole cards, best 5 of 7
+    OmahaHiLo,      // 4 hole cards, exactly 2 + 3 board, 8-or-better low
+    ShortDeck,      // 6+ Hold'em: 36-card deck, flush beats full house
+}
+
+impl GameVariant {
+    /// Number of hole cards dealt to each player
+    pub fn hole_cards(&self) -> usize {
+        match self {
+            GameVariant::TexasHoldem | GameVariant::ShortDeck => HOLE_CARDS,
+            GameVariant::OmahaHiLo => OMAHA_HOLE_CARDS,
+        }
+    }
+    
+    /// Number of cards in the deck
+    pub fn deck_size(&self) -> usize {
+        match self {
+            GameVariant::TexasHoldem | GameVariant::OmahaHiLo => DECK_SIZE,
+            GameVariant::ShortDeck => SHORT_DECK_SIZE,
+        }
+    }
+}
+
+/// Ante format posted at the start of each hand
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AnteType {
+    #[default]
+    None,           // No antes
+    Standard,       // Every dealt-in player posts the ante
+    BigBlind,       // Big blind posts the ante for the whole table
+}
+
+/// Voluntary straddle allowed at a table
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum StraddleType {
+    #[default]
+    None,           // No straddles
+    UnderTheGun,    // Seat after the big blind may straddle
+    Button,         // Dealer may straddle, action starts with the small blind
+}
+
 /// Player action types
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum PlayerAction {
@@ -46,6 +92,7 @@ pub enum PlayerStatus {
     Active,         // In the hand
     Folded,         // Folded this hand
     AllIn,          // All-in
+    SittingOut,     // Seated but not dealt in
     Left,           // Left the game
 }
 
@@ -52,3 +52,15 @@ export async function airdropSol(
   );
   await connection.confirmTransaction(signature);
 }
+
+/**
+ * Helper function to fetch the hand number and action sequence
+ * every player action must echo
+ */
+export async function getActionSeq(
+  program: any,
+  gamePda: anchor.web3.PublicKey
+): Promise<[anchor.BN, anchor.BN]> {
+  const game = await program.account.game.fetch(gamePda);
+  return [game.handNumber, game.actionSeq];
+}
@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ArciumPoker } from "../target/types/arcium_poker";
 import { expect } from "chai";
-import { getGamePda, getPlayerStatePda, airdropSol } from "./helpers";
+import { getGamePda, getPlayerStatePda, airdropSol, getActionSeq } from "./helpers";
 
 describe("Betting Tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -83,7 +83,7 @@ describe("Betting Tests", () => {
       const currentPlayerState = playerStates[currentPlayerIdx];
 
       await program.methods
-        .playerAction({ fold: {} })
+        .playerAction({ fold: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: currentPlayerState,
@@ -115,7 +115,7 @@ describe("Betting Tests", () => {
 
       try {
         await program.methods
-          .playerAction({ fold: {} })
+          .playerAction({ fold: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: wrongPlayerState,
@@ -147,7 +147,7 @@ describe("Betting Tests", () => {
       for (let i = 0; i < 3; i++) {
         let game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ call: {} })
+          .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -160,7 +160,7 @@ describe("Betting Tests", () => {
       // Now in Flop stage with no bet - players can check
       let game = await program.account.game.fetch(gamePda);
       await program.methods
-        .playerAction({ check: {} })
+        .playerAction({ check: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -186,7 +186,7 @@ describe("Betting Tests", () => {
       
       // First player bets
       await program.methods
-        .playerAction({ bet: { amount: new anchor.BN(200) } })
+        .playerAction({ bet: { amount: new anchor.BN(200) } }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -200,7 +200,7 @@ describe("Betting Tests", () => {
       // Next player tries to check (should fail)
       try {
         await program.methods
-          .playerAction({ check: {} })
+          .playerAction({ check: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -232,7 +232,7 @@ describe("Betting Tests", () => {
       const betAmount = new anchor.BN(200);
 
       await program.methods
-        .playerAction({ bet: { amount: betAmount } })
+        .playerAction({ bet: { amount: betAmount } }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -264,7 +264,7 @@ describe("Betting Tests", () => {
 
       try {
         await program.methods
-          .playerAction({ bet: { amount: betAmount } })
+          .playerAction({ bet: { amount: betAmount } }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -294,7 +294,7 @@ describe("Betting Tests", () => {
       
       // First player bets 200
       await program.methods
-        .playerAction({ bet: { amount: new anchor.BN(200) } })
+        .playerAction({ bet: { amount: new anchor.BN(200) } }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -308,7 +308,7 @@ describe("Betting Tests", () => {
       // Next player tries to raise to 250 (only 50 more, should be at least 200 more)
       try {
         await program.methods
-          .playerAction({ raise: { amount: new anchor.BN(250) } })
+          .playerAction({ raise: { amount: new anchor.BN(250) } }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -340,7 +340,7 @@ describe("Betting Tests", () => {
       const currentPlayerIdx = game.currentPlayerIndex;
 
       await program.methods
-        .playerAction({ allIn: {} })
+        .playerAction({ allIn: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[currentPlayerIdx],
@@ -373,7 +373,7 @@ describe("Betting Tests", () => {
 
       // Player goes all-in
       await program.methods
-        .playerAction({ allIn: {} })
+        .playerAction({ allIn: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[allInPlayerIdx],
@@ -386,7 +386,7 @@ describe("Betting Tests", () => {
       for (let i = 0; i < 2; i++) {
         game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ call: {} })
+          .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -451,7 +451,7 @@ describe("Betting Tests", () => {
       for (let i = 0; i < 4; i++) {
         let game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ call: {} })
+          .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ArciumPoker } from "../target/types/arcium_poker";
 import { expect } from "chai";
-import { getGamePda, getPlayerStatePda, airdropSol } from "./helpers";
+import { getGamePda, getPlayerStatePda, airdropSol, getActionSeq } from "./helpers";
 
 describe("Edge Cases and Security Tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -88,7 +88,7 @@ describe("Edge Cases and Security Tests", () => {
       for (let i = 0; i < 3; i++) {
         const game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ call: {} })
+          .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -334,7 +334,7 @@ describe("Edge Cases and Security Tests", () => {
 
       try {
         await program.methods
-          .playerAction({ fold: {} })
+          .playerAction({ fold: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[wrongPlayerIdx],
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ArciumPoker } from "../target/types/arcium_poker";
 import { expect } from "chai";
+import { getActionSeq } from "./helpers";
 
 describe("Game Flow Tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -222,7 +223,7 @@ describe("Game Flow Tests", () => {
       for (let i = 0; i < 3; i++) {
         const game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ call: {} })
+          .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -242,7 +243,7 @@ describe("Game Flow Tests", () => {
       for (let i = 0; i < 3; i++) {
         let game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ call: {} })
+          .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -256,7 +257,7 @@ describe("Game Flow Tests", () => {
       for (let i = 0; i < 3; i++) {
         let game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ check: {} })
+          .playerAction({ check: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -278,7 +279,7 @@ describe("Game Flow Tests", () => {
           let game = await program.account.game.fetch(gamePda);
           const action = round === 0 ? { call: {} } : { check: {} };
           await program.methods
-            .playerAction(action)
+            .playerAction(action, ...(await getActionSeq(program, gamePda)))
             .accounts({
               game: gamePda,
               playerState: playerStates[game.currentPlayerIndex],
@@ -301,7 +302,7 @@ describe("Game Flow Tests", () => {
           let game = await program.account.game.fetch(gamePda);
           const action = round === 0 ? { call: {} } : { check: {} };
           await program.methods
-            .playerAction(action)
+            .playerAction(action, ...(await getActionSeq(program, gamePda)))
             .accounts({
               game: gamePda,
               playerState: playerStates[game.currentPlayerIndex],
@@ -334,7 +335,7 @@ describe("Game Flow Tests", () => {
       for (let i = 0; i < 2; i++) {
         const game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ fold: {} })
+          .playerAction({ fold: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -364,7 +365,7 @@ describe("Game Flow Tests", () => {
       for (let i = 0; i < 2; i++) {
         const game = await program.account.game.fetch(gamePda);
         await program.methods
-          .playerAction({ fold: {} })
+          .playerAction({ fold: {} }, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -374,9 +375,9 @@ describe("Game Flow Tests", () => {
           .rpc();
       }
 
-      // Start new hand
+      // Start new hand (fresh entropy for the reshuffle)
       await program.methods
-        .newHand()
+        .newHand(playerEntropy)
         .accounts({
           game: gamePda,
           authority: provider.wallet.publicKey,
@@ -385,7 +386,8 @@ describe("Game Flow Tests", () => {
 
       const game = await program.account.game.fetch(gamePda);
       expect(game.stage).to.deep.equal({ preFlop: {} });
-      expect(game.pot.toNumber()).to.equal(0);
+      expect(game.handNumber.toNumber()).to.equal(2);
+      expect(game.deckInitialized).to.be.true;
     });
   });
 
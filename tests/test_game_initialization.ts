@@ -164,7 +164,7 @@ describe("Game Initialization Tests", () => {
             null,
             null,
             null,
-            10 // MAX_PLAYERS is 6
+            11 // MAX_PLAYERS is 10
           )
           .accounts({
             authority: authority.publicKey,
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ArciumPoker } from "../target/types/arcium_poker";
 import { expect } from "chai";
+import { getActionSeq } from "./helpers";
 
 describe("Side Pot Tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -87,7 +88,7 @@ describe("Side Pot Tests", () => {
       // Player 1 (2000 chips) goes all-in
       let game = await program.account.game.fetch(gamePda);
       await program.methods
-        .playerAction({ allIn: {} })
+        .playerAction({ allIn: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -99,7 +100,7 @@ describe("Side Pot Tests", () => {
       // Player 2 (5000 chips) goes all-in
       game = await program.account.game.fetch(gamePda);
       await program.methods
-        .playerAction({ allIn: {} })
+        .playerAction({ allIn: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -111,7 +112,7 @@ describe("Side Pot Tests", () => {
       // Player 3 (10000 chips) calls
       game = await program.account.game.fetch(gamePda);
       await program.methods
-        .playerAction({ call: {} })
+        .playerAction({ call: {} }, ...(await getActionSeq(program, gamePda)))
         .accounts({
           game: gamePda,
           playerState: playerStates[game.currentPlayerIndex],
@@ -191,7 +192,7 @@ describe("Side Pot Tests", () => {
         const action = i < 3 ? { allIn: {} } : { call: {} };
         
         await program.methods
-          .playerAction(action)
+          .playerAction(action, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],
@@ -261,7 +262,7 @@ describe("Side Pot Tests", () => {
         const action = i < 2 ? { allIn: {} } : { call: {} };
         
         await program.methods
-          .playerAction(action)
+          .playerAction(action, ...(await getActionSeq(program, gamePda)))
           .accounts({
             game: gamePda,
             playerState: playerStates[game.currentPlayerIndex],